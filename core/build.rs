@@ -0,0 +1,631 @@
+use anyhow::Result;
+use quote::quote;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    env, fs, io,
+    path::Path,
+};
+
+fn encode_browser_name(name: &str) -> u8 {
+    match name {
+        "ie" => 1,
+        "edge" => 2,
+        "firefox" => 3,
+        "chrome" => 4,
+        "safari" => 5,
+        "opera" => 6,
+        "ios_saf" => 7,
+        "op_mini" => 8,
+        "android" => 9,
+        "bb" => 10,
+        "op_mob" => 11,
+        "and_chr" => 12,
+        "and_ff" => 13,
+        "ie_mob" => 14,
+        "and_uc" => 15,
+        "samsung" => 16,
+        "and_qq" => 17,
+        "baidu" => 18,
+        "kaios" => 19,
+        _ => unreachable!("unknown browser name"),
+    }
+}
+
+#[derive(Deserialize)]
+struct Caniuse {
+    agents: HashMap<String, Agent>,
+    data: BTreeMap<String, Feature>,
+}
+
+#[derive(Deserialize)]
+struct Agent {
+    usage_global: HashMap<String, f32>,
+    version_list: Vec<VersionDetail>,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+struct VersionDetail {
+    version: String,
+    global_usage: f32,
+    release_date: Option<i64>,
+    prefix: String,
+}
+
+#[derive(Deserialize)]
+struct Feature {
+    stats: HashMap<String, HashMap<String, String>>,
+}
+
+fn main() -> Result<()> {
+    emit_data_versions()?;
+    build_electron_to_chromium()?;
+    build_node_versions()?;
+    build_node_release_schedule()?;
+    build_caniuse_global()?;
+    build_caniuse_region()?;
+    build_mdn_compat()?;
+    build_bundled_distribs()?;
+
+    Ok(())
+}
+
+fn emit_data_versions() -> Result<()> {
+    #[derive(Deserialize)]
+    struct PackageJson {
+        version: String,
+    }
+
+    for (package, key) in [
+        ("caniuse", "CANIUSE"),
+        ("electron-to-chromium", "ELECTRON_TO_CHROMIUM"),
+        ("node-releases", "NODE_RELEASES"),
+    ] {
+        let path = format!(
+            "{}/vendor/{}/package.json",
+            env::var("CARGO_MANIFEST_DIR")?,
+            package
+        );
+        println!("cargo:rerun-if-changed=vendor/{}/package.json", package);
+        let content = fs::read(&path).map_err(|err| {
+            anyhow::anyhow!(
+                "failed to read '{}': {}; make sure submodules are checked out",
+                path,
+                err
+            )
+        })?;
+        let version = serde_json::from_slice::<PackageJson>(&content)?.version;
+        println!("cargo:rustc-env=BROWSERSLIST_DATA_{}={}", key, version);
+    }
+
+    Ok(())
+}
+
+fn build_electron_to_chromium() -> Result<()> {
+    println!("cargo:rerun-if-changed=vendor/electron-to-chromium/versions.json");
+
+    let path = format!("{}/electron-to-chromium.json", env::var("OUT_DIR")?);
+
+    let mut data = serde_json::from_slice::<BTreeMap<String, String>>(&fs::read(format!(
+        "{}/vendor/electron-to-chromium/versions.json",
+        env::var("CARGO_MANIFEST_DIR")?
+    ))?)?
+    .into_iter()
+    .map(|(electron_version, chromium_version)| {
+        (electron_version.parse::<f32>().unwrap(), chromium_version)
+    })
+    .collect::<Vec<_>>();
+    data.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap());
+
+    fs::write(path, serde_json::to_string(&data)?)?;
+
+    Ok(())
+}
+
+fn build_node_versions() -> Result<()> {
+    #[derive(Deserialize)]
+    struct NodeRelease {
+        version: String,
+    }
+
+    println!("cargo:rerun-if-changed=vendor/node-releases/data/processed/envs.json");
+
+    let path = format!("{}/node-versions.json", env::var("OUT_DIR")?);
+
+    let releases: Vec<NodeRelease> = serde_json::from_slice(&fs::read(format!(
+        "{}/vendor/node-releases/data/processed/envs.json",
+        env::var("CARGO_MANIFEST_DIR")?
+    ))?)?;
+
+    fs::write(
+        path,
+        serde_json::to_string(
+            &releases
+                .into_iter()
+                .map(|release| release.version)
+                .collect::<Vec<_>>(),
+        )?,
+    )?;
+
+    Ok(())
+}
+
+fn build_node_release_schedule() -> Result<()> {
+    println!(
+        "cargo:rerun-if-changed=vendor/node-releases/data/release-schedule/release-schedule.json"
+    );
+
+    #[derive(Deserialize)]
+    struct NodeRelease {
+        start: String,
+        end: String,
+    }
+
+    let path = format!("{}/node-release-schedule.json", env::var("OUT_DIR")?);
+
+    let schedule: HashMap<String, NodeRelease> = serde_json::from_slice(&fs::read(format!(
+        "{}/vendor/node-releases/data/release-schedule/release-schedule.json",
+        env::var("CARGO_MANIFEST_DIR")?
+    ))?)?;
+
+    fs::write(
+        path,
+        serde_json::to_string(
+            &schedule
+                .into_iter()
+                .map(|(version, release)| {
+                    (
+                        version.trim_start_matches('v').to_owned(),
+                        (release.start, release.end),
+                    )
+                })
+                .collect::<HashMap<_, _>>(),
+        )?,
+    )?;
+
+    Ok(())
+}
+
+fn build_caniuse_global() -> Result<()> {
+    #[derive(Serialize)]
+    struct BrowserStat {
+        name: String,
+        version_list: Vec<VersionDetail>,
+    }
+
+    let out_dir = env::var("OUT_DIR")?;
+
+    let data = parse_caniuse_global()?;
+
+    let browsers = data
+        .agents
+        .iter()
+        .map(|(name, agent)| {
+            (
+                name,
+                BrowserStat {
+                    name: name.to_string(),
+                    version_list: agent.version_list.clone(),
+                },
+            )
+        })
+        .collect::<HashMap<_, _>>();
+    fs::write(
+        format!("{}/caniuse-browsers.json", &out_dir),
+        &serde_json::to_string(&browsers)?,
+    )?;
+
+    let mut global_usage = data
+        .agents
+        .iter()
+        .flat_map(|(name, agent)| {
+            agent
+                .usage_global
+                .iter()
+                .map(|(version, usage)| (encode_browser_name(name), version.clone(), usage))
+        })
+        .collect::<Vec<_>>();
+    global_usage.sort_unstable_by(|(_, _, a), (_, _, b)| b.partial_cmp(a).unwrap());
+    fs::write(
+        format!("{}/caniuse-global-usage.json", &out_dir),
+        &serde_json::to_string(&global_usage)?,
+    )?;
+
+    let features_dir = format!("{}/features", &out_dir);
+    if matches!(fs::File::open(&features_dir), Err(e) if e.kind() == io::ErrorKind::NotFound) {
+        fs::create_dir(&features_dir)?;
+    }
+    for (name, feature) in &data.data {
+        fs::write(
+            format!("{}/{}.json", &features_dir, name),
+            serde_json::to_string(
+                &feature
+                    .stats
+                    .iter()
+                    .flat_map(|(name, versions)| {
+                        versions
+                            .iter()
+                            .filter(|(_, stat)| stat.starts_with('y') || stat.starts_with('a'))
+                            .map(|(version, stat)| {
+                                (
+                                    encode_browser_name(name),
+                                    version.clone(),
+                                    stat.split(' ').any(|flag| flag == "x"),
+                                    stat.starts_with('a'),
+                                )
+                            })
+                    })
+                    .collect::<Vec<_>>(),
+            )?,
+        )?;
+    }
+    let features = data.data.keys().collect::<Vec<_>>();
+    let tokens = quote! {{
+        use alloc::vec::Vec;
+        use serde_json::from_str;
+        use crate::data::Lazy;
+
+        match name {
+            #( #features => {
+                static STAT: Lazy<Vec<(&'static str, &'static str, bool, bool)>> = Lazy::new(|| {
+                    from_str::<Vec<(u8, &'static str, bool, bool)>>(include_str!(concat!(env!("OUT_DIR"), "/features/", #features, ".json")))
+                        .unwrap()
+                        .into_iter()
+                        .map(|(browser, version, prefixed, partial)| (crate::data::browser_name::decode_browser_name(browser), version, prefixed, partial))
+                        .collect()
+                });
+                Some(&*STAT)
+            }, )*
+            _ => None,
+        }
+    }};
+    fs::write(
+        format!("{}/caniuse-feature-matching.rs", &out_dir),
+        tokens.to_string(),
+    )?;
+    fs::write(
+        format!("{}/caniuse-feature-names.rs", &out_dir),
+        quote! { [#( #features ),*] }.to_string(),
+    )?;
+
+    Ok(())
+}
+
+/// Build a sorted table of browser names (and `node`) with versions from bundled data,
+/// which can be searched in const context.
+///
+/// Mobile browsers also have all versions of their desktop browsers,
+/// since queries can produce them with `mobile_to_desktop` option.
+fn build_bundled_distribs() -> Result<()> {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR")?;
+    let caniuse = parse_caniuse_global()?;
+
+    let mut distribs = BTreeSet::new();
+    for (name, agent) in &caniuse.agents {
+        let desktop = match name.as_str() {
+            "and_chr" | "android" => caniuse.agents.get("chrome"),
+            "and_ff" => caniuse.agents.get("firefox"),
+            "ie_mob" => caniuse.agents.get("ie"),
+            "op_mob" => caniuse.agents.get("opera"),
+            _ => None,
+        };
+        distribs.extend(
+            [Some(agent), desktop]
+                .into_iter()
+                .flatten()
+                .flat_map(|agent| &agent.version_list)
+                .map(|version| (name.clone(), version.version.clone())),
+        );
+    }
+
+    let electron = serde_json::from_slice::<BTreeMap<String, String>>(&fs::read(format!(
+        "{}/vendor/electron-to-chromium/versions.json",
+        &manifest_dir
+    ))?)?;
+    distribs.extend(
+        electron
+            .into_values()
+            .map(|version| (String::from("chrome"), version)),
+    );
+
+    #[derive(Deserialize)]
+    struct NodeRelease {
+        version: String,
+    }
+    let node = serde_json::from_slice::<Vec<NodeRelease>>(&fs::read(format!(
+        "{}/vendor/node-releases/data/processed/envs.json",
+        &manifest_dir
+    ))?)?;
+    distribs.extend(
+        node.into_iter()
+            .map(|release| (String::from("node"), release.version)),
+    );
+
+    let (names, versions): (Vec<_>, Vec<_>) = distribs.into_iter().unzip();
+    fs::write(
+        format!("{}/bundled-distribs.rs", env::var("OUT_DIR")?),
+        quote! { &[#( (#names, #versions) ),*] }.to_string(),
+    )?;
+
+    Ok(())
+}
+
+fn parse_caniuse_global() -> Result<Caniuse> {
+    println!("cargo:rerun-if-changed=vendor/caniuse/fulldata-json/data-2.0.json");
+
+    Ok(serde_json::from_slice(&fs::read(format!(
+        "{}/vendor/caniuse/fulldata-json/data-2.0.json",
+        env::var("CARGO_MANIFEST_DIR")?
+    ))?)?)
+}
+
+fn build_caniuse_region() -> Result<()> {
+    #[derive(Deserialize)]
+    struct RegionData {
+        data: HashMap<String, HashMap<String, Option<f32>>>,
+    }
+
+    let files = fs::read_dir(format!(
+        "{}/vendor/caniuse/region-usage-json",
+        env::var("CARGO_MANIFEST_DIR")?
+    ))?
+    .map(|entry| entry.map_err(anyhow::Error::from))
+    .collect::<Result<Vec<_>>>()?;
+
+    files.iter().for_each(|entry| {
+        println!(
+            "cargo:rerun-if-changed=vendor/caniuse/region-usage-json/{}",
+            entry.file_name().into_string().unwrap()
+        )
+    });
+
+    let out_dir = env::var("OUT_DIR")?;
+
+    let Caniuse { agents, .. } = parse_caniuse_global()?;
+
+    let region_dir = format!("{}/region", &out_dir);
+    if matches!(fs::File::open(&region_dir), Err(e) if e.kind() == io::ErrorKind::NotFound) {
+        fs::create_dir(&region_dir)?;
+    }
+
+    for file in &files {
+        let RegionData { data } = serde_json::from_slice(&fs::read(file.path())?)?;
+        let mut usage = data
+            .into_iter()
+            .flat_map(|(name, stat)| {
+                let agent = agents.get(&name).unwrap();
+                stat.into_iter().filter_map(move |(version, usage)| {
+                    let version = if version.as_str() == "0" {
+                        agent.version_list.last().unwrap().version.clone()
+                    } else {
+                        version
+                    };
+                    usage.map(|usage| (encode_browser_name(&name), version, usage))
+                })
+            })
+            .collect::<Vec<_>>();
+        usage.sort_unstable_by(|(_, _, a), (_, _, b)| b.partial_cmp(a).unwrap());
+        fs::write(
+            format!("{}/region/{}", &out_dir, file.file_name().to_str().unwrap()),
+            serde_json::to_string(&usage)?,
+        )?;
+    }
+    let regions = files
+        .iter()
+        .map(|entry| {
+            entry
+                .path()
+                .file_stem()
+                .unwrap()
+                .to_str()
+                .map(|s| s.to_owned())
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let tokens = quote! {{
+        use alloc::vec::Vec;
+        use serde_json::from_str;
+        use crate::data::Lazy;
+
+        match region {
+            #( #regions => {
+                static USAGE: Lazy<Vec<(&'static str, &'static str, f32)>> = Lazy::new(|| {
+                    from_str::<Vec<(u8, &'static str, f32)>>(include_str!(concat!(env!("OUT_DIR"), "/region/", #regions, ".json")))
+                        .unwrap()
+                        .into_iter()
+                        .map(|(browser, version, usage)| (crate::data::browser_name::decode_browser_name(browser), version, usage))
+                        .collect()
+                });
+                Some(&*USAGE)
+            }, )*
+            _ => None,
+        }
+    }};
+    fs::write(
+        format!("{}/caniuse-region-matching.rs", &out_dir),
+        tokens.to_string(),
+    )?;
+
+    Ok(())
+}
+
+/// Map browser key of MDN browser-compat-data to name of Can I Use agent, or `node`.
+fn mdn_browser_to_caniuse(name: &str) -> Option<&'static str> {
+    match name {
+        "chrome" => Some("chrome"),
+        "chrome_android" => Some("and_chr"),
+        "edge" => Some("edge"),
+        "firefox" => Some("firefox"),
+        "firefox_android" => Some("and_ff"),
+        "ie" => Some("ie"),
+        "nodejs" => Some("node"),
+        "opera" => Some("opera"),
+        "opera_android" => Some("op_mob"),
+        "safari" => Some("safari"),
+        "safari_ios" => Some("ios_saf"),
+        "samsunginternet_android" => Some("samsung"),
+        "webview_android" => Some("android"),
+        _ => None,
+    }
+}
+
+/// Directories of features in MDN browser-compat-data.
+const MDN_CATEGORIES: &[&str] = &[
+    "api",
+    "css",
+    "html",
+    "http",
+    "javascript",
+    "manifests",
+    "mathml",
+    "svg",
+    "webassembly",
+    "webdriver",
+    "webextensions",
+];
+
+/// Build features from vendored MDN browser-compat-data,
+/// and also from the small subset in `fixtures/browser-compat-data.json` which is used by tests.
+fn build_mdn_compat() -> Result<()> {
+    println!("cargo:rerun-if-changed=vendor/browser-compat-data");
+    println!("cargo:rerun-if-changed=fixtures/browser-compat-data.json");
+
+    let out_dir = env::var("OUT_DIR")?;
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR")?;
+
+    let vendor_dir = Path::new(&manifest_dir).join("vendor/browser-compat-data");
+    if let Err(err) = fs::read_dir(vendor_dir.join("api")) {
+        anyhow::bail!(
+            "failed to read '{}': {}; make sure submodules are checked out",
+            vendor_dir.display(),
+            err
+        );
+    }
+    let mut data = serde_json::Value::Object(Default::default());
+    for category in MDN_CATEGORIES {
+        let dir = vendor_dir.join(category);
+        if dir.is_dir() {
+            read_mdn_dir(&dir, &mut data)?;
+        }
+    }
+    write_mdn_features(&data, format!("{}/mdn-features.json", &out_dir))?;
+
+    let fixture = fs::read(format!(
+        "{}/fixtures/browser-compat-data.json",
+        &manifest_dir
+    ))?;
+    write_mdn_features(
+        &serde_json::from_slice(&fixture)?,
+        format!("{}/mdn-features-fixture.json", &out_dir),
+    )?;
+
+    Ok(())
+}
+
+/// Read JSON files of browser-compat-data recursively, merging them into one tree.
+fn read_mdn_dir(dir: &Path, data: &mut serde_json::Value) -> Result<()> {
+    let mut entries = fs::read_dir(dir)?.collect::<io::Result<Vec<_>>>()?;
+    entries.sort_by_key(|entry| entry.path());
+    for entry in entries {
+        let path = entry.path();
+        if path.is_dir() {
+            read_mdn_dir(&path, data)?;
+        } else if path.extension().is_some_and(|ext| ext == "json") {
+            merge_json(data, serde_json::from_slice(&fs::read(&path)?)?);
+        }
+    }
+    Ok(())
+}
+
+fn merge_json(target: &mut serde_json::Value, source: serde_json::Value) {
+    match (target, source) {
+        (serde_json::Value::Object(target), serde_json::Value::Object(source)) => {
+            for (key, value) in source {
+                match target.get_mut(&key) {
+                    Some(existing) => merge_json(existing, value),
+                    None => {
+                        target.insert(key, value);
+                    }
+                }
+            }
+        }
+        (target, source) => *target = source,
+    }
+}
+
+fn write_mdn_features(data: &serde_json::Value, path: String) -> Result<()> {
+    let mut features = BTreeMap::new();
+    if let Some(data) = data.as_object() {
+        for (category, value) in data {
+            if category != "browsers" && !category.starts_with("__") {
+                collect_mdn_features(category.clone(), value, &mut features);
+            }
+        }
+    }
+    fs::write(path, serde_json::to_string(&features)?)?;
+    Ok(())
+}
+
+/// Support statements of a feature, each of which is a tuple of browser, version added, version removed
+/// and whether it's supported with prefix or alternative name.
+type MdnStatements = Vec<(&'static str, String, Option<String>, bool)>;
+
+/// Collect support statements of features recursively.
+///
+/// Statements which require flags or don't have exact versions are skipped.
+fn collect_mdn_features(
+    path: String,
+    value: &serde_json::Value,
+    features: &mut BTreeMap<String, MdnStatements>,
+) {
+    let Some(value) = value.as_object() else {
+        return;
+    };
+
+    if let Some(support) = value
+        .get("__compat")
+        .and_then(|compat| compat.get("support"))
+        .and_then(|support| support.as_object())
+    {
+        let statements = support
+            .iter()
+            .filter_map(|(browser, statements)| {
+                mdn_browser_to_caniuse(browser).map(|browser| (browser, statements))
+            })
+            .flat_map(|(browser, statements)| {
+                let statements = match statements {
+                    serde_json::Value::Array(statements) => statements.iter().collect(),
+                    statement => vec![statement],
+                };
+                statements.into_iter().filter_map(move |statement| {
+                    if statement.get("flags").is_some() {
+                        return None;
+                    }
+                    let version_added = statement.get("version_added")?.as_str()?;
+                    let version_removed = match statement.get("version_removed") {
+                        Some(serde_json::Value::String(version)) => {
+                            Some(version.trim_start_matches('≤').to_string())
+                        }
+                        Some(serde_json::Value::Bool(true)) => return None,
+                        _ => None,
+                    };
+                    let prefixed = statement.get("prefix").is_some()
+                        || statement.get("alternative_name").is_some();
+                    Some((
+                        browser,
+                        version_added.trim_start_matches('≤').to_string(),
+                        version_removed,
+                        prefixed,
+                    ))
+                })
+            })
+            .collect::<Vec<_>>();
+        features.insert(path.clone(), statements);
+    }
+
+    for (key, value) in value {
+        if !key.starts_with("__") {
+            collect_mdn_features(format!("{}.{}", path, key), value, features);
+        }
+    }
+}
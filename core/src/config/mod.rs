@@ -175,7 +175,7 @@ fn find_config<P: AsRef<Path>>(path: P) -> Result<Either<String, PkgConfig>, Err
     Ok(Either::Left(String::from("defaults")))
 }
 
-fn get_env(opts: &Opts) -> Cow<str> {
+fn get_env(opts: &Opts) -> Cow<'_, str> {
     opts.env
         .as_ref()
         .map(Cow::from)
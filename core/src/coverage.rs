@@ -0,0 +1,168 @@
+use crate::{
+    data::caniuse::{get_browser_stat, region::get_usage_by_region, CANIUSE_GLOBAL_USAGE},
+    error::Error,
+    queries::Distrib,
+};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
+use serde::Serialize;
+
+/// Custom usage statistics, which maps browser name to its versions and their usage (in percentage).
+///
+/// It has the same structure as `browserslist-stats.json` of JavaScript-based Browserslist,
+/// so it can be deserialized from that file directly.
+/// Browser names are case-insensitive and can be aliases like `fx`, as in queries.
+pub type CustomStats = BTreeMap<String, BTreeMap<String, f32>>;
+
+/// Source of usage statistics for calculating [`coverage`].
+#[derive(Clone, Copy, Debug)]
+pub enum Stats<'a> {
+    /// Global usage from Can I Use.
+    Global,
+
+    /// Usage in a country like `US` or a continent like `alt-eu` from Can I Use.
+    Region(&'a str),
+
+    /// Custom usage statistics.
+    Custom(&'a CustomStats),
+}
+
+/// Result of [`coverage`].
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Coverage {
+    total: f32,
+    by_browser: BTreeMap<&'static str, f32>,
+}
+
+impl Coverage {
+    /// Return total coverage (in percentage).
+    #[inline]
+    pub fn total(&self) -> f32 {
+        self.total
+    }
+
+    /// Return coverage (in percentage) of each browser.
+    ///
+    /// Browsers whose versions aren't found in statistics are still listed with `0.0`.
+    #[inline]
+    pub fn by_browser(&self) -> &BTreeMap<&'static str, f32> {
+        &self.by_browser
+    }
+}
+
+/// Calculate what share of users are covered by the given browsers.
+///
+/// Like JavaScript-based Browserslist,
+/// if a version isn't found in statistics, version `0` of that browser is used instead.
+///
+/// ```
+/// use browserslist::{coverage, resolve, Opts, Stats};
+///
+/// let distribs = resolve(["> 1%"], &Opts::new()).unwrap();
+/// let global = coverage(&distribs, Stats::Global).unwrap();
+/// assert!(global.total() > 0.0);
+/// assert!(global.by_browser().contains_key("chrome"));
+///
+/// let in_us = coverage(&distribs, Stats::Region("US")).unwrap();
+/// assert!(in_us.total() > 0.0);
+/// ```
+pub fn coverage(distribs: &[Distrib], stats: Stats) -> Result<Coverage, Error> {
+    let usage_of = usage_lookup(stats)?;
+
+    Ok(distribs
+        .iter()
+        .fold(Coverage::default(), |mut coverage, distrib| {
+            let usage = usage_of(distrib.name(), distrib.version())
+                .or_else(|| usage_of(distrib.name(), "0"))
+                .unwrap_or_default();
+            coverage.total += usage;
+            *coverage.by_browser.entry(distrib.name()).or_default() += usage;
+            coverage
+        }))
+}
+
+/// Create a function which looks up usage of a browser version from the given statistics.
+pub(crate) fn usage_lookup(
+    stats: Stats<'_>,
+) -> Result<impl Fn(&str, &str) -> Option<f32> + '_, Error> {
+    let data = usage_data(stats)?;
+    Ok(move |name: &str, version: &str| match stats {
+        Stats::Custom(stats) => stats
+            .iter()
+            .filter(|(key, _)| custom_browser_name(key) == Some(name))
+            .find_map(|(_, versions)| versions.get(version))
+            .copied(),
+        _ => data
+            .iter()
+            .find(|(n, v, _)| *n == name && *v == version)
+            .map(|(_, _, usage)| *usage),
+    })
+}
+
+/// Normalize browser name from custom statistics to name of Can I Use agent.
+///
+/// It returns `None` for browsers unknown to Can I Use.
+pub(crate) fn custom_browser_name(name: &str) -> Option<&'static str> {
+    get_browser_stat(name, false).map(|(name, _)| name)
+}
+
+/// Return usage data from Can I Use for the given statistics.
+///
+/// It's empty for custom statistics.
+pub(crate) fn usage_data(
+    stats: Stats<'_>,
+) -> Result<&'static [(&'static str, &'static str, f32)], Error> {
+    match stats {
+        Stats::Global => Ok(&CANIUSE_GLOBAL_USAGE),
+        Stats::Region(region) => get_usage_by_region(region)
+            .map(|data| &**data)
+            .ok_or_else(|| Error::UnknownRegion(region.to_string())),
+        Stats::Custom(..) => Ok(&[]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{opts::Opts, resolve};
+
+    #[test]
+    fn global() {
+        let distribs = resolve(["> 1%, node 16.0.0"], &Opts::new()).unwrap();
+        let coverage = coverage(&distribs, Stats::Global).unwrap();
+        let expected = distribs.iter().map(|d| d.global_usage()).sum::<f32>();
+        assert!((coverage.total() - expected).abs() < 1e-3);
+        assert_eq!(coverage.by_browser()["node"], 0.0);
+        assert!((coverage.by_browser().values().sum::<f32>() - coverage.total()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn region() {
+        let distribs = resolve(["> 1% in US"], &Opts::new()).unwrap();
+        assert_eq!(
+            coverage(&distribs, Stats::Region("us")),
+            coverage(&distribs, Stats::Region("US"))
+        );
+        assert!(coverage(&distribs, Stats::Region("alt-EU")).is_ok());
+        assert_eq!(
+            coverage(&distribs, Stats::Region("XX")),
+            Err(Error::UnknownRegion("XX".into()))
+        );
+    }
+
+    #[test]
+    fn custom() {
+        let stats: CustomStats =
+            serde_json::from_str(r#"{ "Chrome": { "100": 10.5, "0": 1 }, "fx": { "100": 5 } }"#)
+                .unwrap();
+        let distribs =
+            resolve(["chrome 100, chrome 99, firefox 100, ie 11"], &Opts::new()).unwrap();
+        let coverage = coverage(&distribs, Stats::Custom(&stats)).unwrap();
+        assert_eq!(coverage.total(), 16.5);
+        assert_eq!(coverage.by_browser()["chrome"], 11.5);
+        assert_eq!(coverage.by_browser()["firefox"], 5.0);
+        assert_eq!(coverage.by_browser()["ie"], 0.0);
+    }
+}
@@ -1,8 +1,6 @@
-use super::browser_name::BrowserNameAtom;
-use ahash::AHashMap;
-use once_cell::sync::Lazy;
+use super::Lazy;
+use alloc::{borrow::Cow, collections::BTreeMap, vec::Vec};
 use serde::Deserialize;
-use std::borrow::Cow;
 
 pub(crate) mod features;
 pub(crate) mod region;
@@ -11,18 +9,19 @@ pub const ANDROID_EVERGREEN_FIRST: f32 = 37.0;
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct BrowserStat {
-    name: BrowserNameAtom,
+    name: &'static str,
     pub version_list: Vec<VersionDetail>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct VersionDetail {
-    pub version: String,
+    pub version: &'static str,
     pub global_usage: f32,
     pub release_date: Option<i64>,
+    pub prefix: &'static str,
 }
 
-pub type CaniuseData = AHashMap<BrowserNameAtom, BrowserStat>;
+pub type CaniuseData = BTreeMap<&'static str, BrowserStat>;
 
 pub static CANIUSE_BROWSERS: Lazy<CaniuseData> = Lazy::new(|| {
     serde_json::from_str(include_str!(concat!(
@@ -32,8 +31,8 @@ pub static CANIUSE_BROWSERS: Lazy<CaniuseData> = Lazy::new(|| {
     .unwrap()
 });
 
-pub static CANIUSE_GLOBAL_USAGE: Lazy<Vec<(BrowserNameAtom, String, f32)>> = Lazy::new(|| {
-    serde_json::from_str::<Vec<(u8, String, f32)>>(include_str!(concat!(
+pub static CANIUSE_GLOBAL_USAGE: Lazy<Vec<(&'static str, &'static str, f32)>> = Lazy::new(|| {
+    serde_json::from_str::<Vec<(u8, &'static str, f32)>>(include_str!(concat!(
         env!("OUT_DIR"),
         "/caniuse-global-usage.json"
     )))
@@ -50,7 +49,7 @@ pub static CANIUSE_GLOBAL_USAGE: Lazy<Vec<(BrowserNameAtom, String, f32)>> = Laz
 });
 
 pub static BROWSER_VERSION_ALIASES: Lazy<
-    AHashMap<BrowserNameAtom, AHashMap<&'static str, &'static str>>,
+    BTreeMap<&'static str, BTreeMap<&'static str, &'static str>>,
 > = Lazy::new(|| {
     let mut aliases = CANIUSE_BROWSERS
         .iter()
@@ -62,10 +61,10 @@ pub static BROWSER_VERSION_ALIASES: Lazy<
                     version
                         .version
                         .split_once('-')
-                        .map(|(bottom, top)| (bottom, top, version.version.as_str()))
+                        .map(|(bottom, top)| (bottom, top, version.version))
                 })
                 .fold(
-                    AHashMap::<&str, &str>::new(),
+                    BTreeMap::<&str, &str>::new(),
                     move |mut aliases, (bottom, top, version)| {
                         let _ = aliases.insert(bottom, version);
                         let _ = aliases.insert(top, version);
@@ -75,12 +74,12 @@ pub static BROWSER_VERSION_ALIASES: Lazy<
             if aliases.is_empty() {
                 None
             } else {
-                Some((name.clone(), aliases))
+                Some((*name, aliases))
             }
         })
-        .collect::<AHashMap<BrowserNameAtom, _>>();
-    let _ = aliases.insert("op_mob".into(), {
-        let mut aliases = AHashMap::new();
+        .collect::<BTreeMap<&'static str, _>>();
+    let _ = aliases.insert("op_mob", {
+        let mut aliases = BTreeMap::new();
         let _ = aliases.insert("59", "58");
         aliases
     });
@@ -88,14 +87,14 @@ pub static BROWSER_VERSION_ALIASES: Lazy<
 });
 
 static ANDROID_TO_DESKTOP: Lazy<BrowserStat> = Lazy::new(|| {
-    let chrome = CANIUSE_BROWSERS.get(&"chrome".into()).unwrap();
-    let mut android = CANIUSE_BROWSERS.get(&"android".into()).unwrap().clone();
+    let chrome = CANIUSE_BROWSERS.get("chrome").unwrap();
+    let mut android = CANIUSE_BROWSERS.get("android").unwrap().clone();
 
     android.version_list = android
         .version_list
         .into_iter()
         .filter(|version| {
-            let version = &version.version;
+            let version = version.version;
             version.starts_with("2.")
                 || version.starts_with("3.")
                 || version.starts_with("4.")
@@ -126,14 +125,14 @@ static ANDROID_TO_DESKTOP: Lazy<BrowserStat> = Lazy::new(|| {
 });
 
 static OPERA_MOBILE_TO_DESKTOP: Lazy<BrowserStat> = Lazy::new(|| {
-    let mut op_mob = CANIUSE_BROWSERS.get(&"opera".into()).unwrap().clone();
+    let mut op_mob = CANIUSE_BROWSERS.get("opera").unwrap().clone();
 
     if let Some(v) = op_mob
         .version_list
         .iter_mut()
-        .find(|version| version.version.as_str() == "10.0-10.1")
+        .find(|version| version.version == "10.0-10.1")
     {
-        v.version = "10".to_string();
+        v.version = "10";
     }
 
     op_mob
@@ -156,18 +155,14 @@ pub fn get_browser_stat(
                 "android" => Some(("android", &ANDROID_TO_DESKTOP)),
                 "op_mob" => Some(("op_mob", &OPERA_MOBILE_TO_DESKTOP)),
                 _ => CANIUSE_BROWSERS
-                    .get(&desktop_name.into())
+                    .get(desktop_name)
                     .map(|stat| (get_mobile_by_desktop_name(desktop_name), stat)),
             }
         } else {
-            CANIUSE_BROWSERS
-                .get(&name.into())
-                .map(|stat| (&*stat.name, stat))
+            CANIUSE_BROWSERS.get(name).map(|stat| (stat.name, stat))
         }
     } else {
-        CANIUSE_BROWSERS
-            .get(&name.into())
-            .map(|stat| (&*stat.name, stat))
+        CANIUSE_BROWSERS.get(name).map(|stat| (stat.name, stat))
     }
 }
 
@@ -188,7 +183,7 @@ fn get_browser_alias(name: &str) -> &str {
     }
 }
 
-fn to_desktop_name(name: &str) -> Option<&'static str> {
+pub(crate) fn to_desktop_name(name: &str) -> Option<&'static str> {
     match name {
         "and_chr" | "android" => Some("chrome"),
         "and_ff" => Some("firefox"),
@@ -208,19 +203,16 @@ fn get_mobile_by_desktop_name(name: &str) -> &'static str {
     }
 }
 
-pub(crate) fn normalize_version<'a>(
-    stat: &'static BrowserStat,
-    version: &'a str,
-) -> Option<&'a str> {
-    if stat.version_list.iter().any(|v| v.version == version) {
-        Some(version)
+pub(crate) fn normalize_version(stat: &'static BrowserStat, version: &str) -> Option<&'static str> {
+    if let Some(detail) = stat.version_list.iter().find(|v| v.version == version) {
+        Some(detail.version)
     } else if let Some(version) = BROWSER_VERSION_ALIASES
-        .get(&stat.name)
+        .get(stat.name)
         .and_then(|aliases| aliases.get(version))
     {
         Some(version)
     } else if stat.version_list.len() == 1 {
-        stat.version_list.first().map(|s| s.version.as_str())
+        stat.version_list.first().map(|s| s.version)
     } else {
         None
     }
@@ -0,0 +1,13 @@
+use alloc::vec::Vec;
+
+/// Supported versions of a feature,
+/// with whether each version requires prefix and whether it's only partially supported.
+type Feature = Vec<(&'static str, &'static str, bool, bool)>;
+
+pub(crate) fn get_feature_stat(name: &str) -> Option<&'static Feature> {
+    include!(concat!(env!("OUT_DIR"), "/caniuse-feature-matching.rs"))
+}
+
+/// Names of all Can I Use features, in alphabetical order.
+pub(crate) static FEATURE_NAMES: &[&str] =
+    &include!(concat!(env!("OUT_DIR"), "/caniuse-feature-names.rs"));
@@ -0,0 +1,17 @@
+use alloc::vec::Vec;
+
+type RegionData = Vec<(&'static str, &'static str, f32)>;
+
+/// Get usage data of a region, whose name is case-insensitive.
+///
+/// Can I Use names countries with uppercase codes like `US`
+/// and continents with lowercase codes like `alt-as`.
+pub(crate) fn get_usage_by_region(region: &str) -> Option<&'static RegionData> {
+    let normalized_region = if region.len() == 2 {
+        region.to_uppercase()
+    } else {
+        region.to_lowercase()
+    };
+    let region = normalized_region.as_str();
+    include!(concat!(env!("OUT_DIR"), "/caniuse-region-matching.rs"))
+}
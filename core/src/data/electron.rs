@@ -1,13 +1,14 @@
+use super::Lazy;
 use crate::error::Error;
+use alloc::{string::ToString, vec::Vec};
 use nom::{
     character::complete::{char, u16},
     combinator::{all_consuming, opt},
     number::complete::float,
     sequence::{pair, terminated},
 };
-use once_cell::sync::Lazy;
 
-pub static ELECTRON_VERSIONS: Lazy<Vec<(f32, String)>> = Lazy::new(|| {
+pub static ELECTRON_VERSIONS: Lazy<Vec<(f32, &'static str)>> = Lazy::new(|| {
     serde_json::from_str(include_str!(concat!(
         env!("OUT_DIR"),
         "/electron-to-chromium.json"
@@ -0,0 +1,21 @@
+use super::Lazy;
+use alloc::{collections::BTreeMap, vec::Vec};
+
+/// Support statements of a feature from MDN browser-compat-data,
+/// each of which is a tuple of browser, version added, version removed
+/// and whether it's supported with prefix or alternative name.
+type Statements = Vec<(&'static str, &'static str, Option<&'static str>, bool)>;
+
+static MDN_FEATURES: Lazy<BTreeMap<&'static str, Statements>> = Lazy::new(|| {
+    #[cfg(not(test))]
+    let content = include_str!(concat!(env!("OUT_DIR"), "/mdn-features.json"));
+    // tests use the subset in `fixtures/browser-compat-data.json`,
+    // so they don't depend on the version of vendored data
+    #[cfg(test)]
+    let content = include_str!(concat!(env!("OUT_DIR"), "/mdn-features-fixture.json"));
+    serde_json::from_str(content).unwrap()
+});
+
+pub(crate) fn get_mdn_feature(path: &str) -> Option<&'static Statements> {
+    MDN_FEATURES.get(path)
+}
@@ -0,0 +1,62 @@
+use alloc::boxed::Box;
+use core::ops::Deref;
+use once_cell::race::OnceBox;
+
+pub(crate) mod caniuse;
+pub(crate) mod electron;
+pub(crate) mod mdn;
+pub(crate) mod node;
+
+/// Lazily initialized value which works without `std`.
+///
+/// Initialization may run more than once when racing between threads,
+/// but only one of the values will be kept.
+pub(crate) struct Lazy<T> {
+    cell: OnceBox<T>,
+    init: fn() -> T,
+}
+
+impl<T> Lazy<T> {
+    pub(crate) const fn new(init: fn() -> T) -> Self {
+        Self {
+            cell: OnceBox::new(),
+            init,
+        }
+    }
+}
+
+impl<T> Deref for Lazy<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.cell.get_or_init(|| Box::new((self.init)()))
+    }
+}
+
+#[doc(hidden)]
+pub(crate) mod browser_name {
+    pub fn decode_browser_name(id: u8) -> &'static str {
+        match id {
+            1 => "ie",
+            2 => "edge",
+            3 => "firefox",
+            4 => "chrome",
+            5 => "safari",
+            6 => "opera",
+            7 => "ios_saf",
+            8 => "op_mini",
+            9 => "android",
+            10 => "bb",
+            11 => "op_mob",
+            12 => "and_chr",
+            13 => "and_ff",
+            14 => "ie_mob",
+            15 => "and_uc",
+            16 => "samsung",
+            17 => "and_qq",
+            18 => "baidu",
+            19 => "kaios",
+            _ => unreachable!("cannot recognize browser id"),
+        }
+    }
+}
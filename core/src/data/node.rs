@@ -1,8 +1,8 @@
-use ahash::AHashMap;
+use super::Lazy;
+use alloc::{collections::BTreeMap, vec::Vec};
 use chrono::{NaiveDate, NaiveDateTime};
-use once_cell::sync::Lazy;
 
-pub static NODE_VERSIONS: Lazy<Vec<String>> = Lazy::new(|| {
+pub static NODE_VERSIONS: Lazy<Vec<&'static str>> = Lazy::new(|| {
     serde_json::from_str(include_str!(concat!(
         env!("OUT_DIR"),
         "/node-versions.json"
@@ -10,25 +10,24 @@ pub static NODE_VERSIONS: Lazy<Vec<String>> = Lazy::new(|| {
     .unwrap()
 });
 
-pub static RELEASE_SCHEDULE: Lazy<AHashMap<String, (NaiveDateTime, NaiveDateTime)>> =
+pub static RELEASE_SCHEDULE: Lazy<BTreeMap<&'static str, (NaiveDateTime, NaiveDateTime)>> =
     Lazy::new(|| {
         let date_format = "%Y-%m-%d";
 
-        serde_json::from_str::<AHashMap<String, (String, String)>>(include_str!(concat!(
-            env!("OUT_DIR"),
-            "/node-release-schedule.json"
-        )))
+        serde_json::from_str::<BTreeMap<&'static str, (&'static str, &'static str)>>(include_str!(
+            concat!(env!("OUT_DIR"), "/node-release-schedule.json")
+        ))
         .unwrap()
         .into_iter()
         .map(|(version, (start, end))| {
             (
                 version,
                 (
-                    NaiveDate::parse_from_str(&start, date_format)
+                    NaiveDate::parse_from_str(start, date_format)
                         .unwrap()
                         .and_hms_opt(0, 0, 0)
                         .unwrap(),
-                    NaiveDate::parse_from_str(&end, date_format)
+                    NaiveDate::parse_from_str(end, date_format)
                         .unwrap()
                         .and_hms_opt(0, 0, 0)
                         .unwrap(),
@@ -0,0 +1,335 @@
+use crate::{
+    data::caniuse::get_browser_stat,
+    error::Error,
+    join_queries,
+    parser::{parse_browserslist_query, Comparator, QueryAtom, Stats, VersionRange},
+    queries::expand_builtin,
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Options for [`describe`].
+#[derive(Clone, Debug, Default)]
+pub struct DescribeOpts {
+    pub(crate) expand: bool,
+}
+
+impl DescribeOpts {
+    /// Create new options with default values.
+    ///
+    /// By default, built-in queries like `defaults` aren't expanded.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Describe what built-in queries like `defaults` and `dead` consist of,
+    /// in parentheses after them.
+    pub fn expand(&mut self, flag: bool) -> &mut Self {
+        self.expand = flag;
+        self
+    }
+}
+
+/// Describe browserslist queries in English, one phrase for each query.
+///
+/// Queries are only parsed but not resolved,
+/// so unknown browsers and versions aren't reported.
+///
+/// ```
+/// use browserslist::{describe, DescribeOpts};
+///
+/// let phrases = describe(["> 0.5%, last 2 versions, not dead"], &DescribeOpts::new()).unwrap();
+/// assert_eq!(
+///     phrases,
+///     [
+///         "versions with more than 0.5% global usage",
+///         "the last 2 released versions of every browser",
+///         "excluding dead browsers",
+///     ]
+/// );
+///
+/// let phrases = describe(["defaults"], DescribeOpts::new().expand(true)).unwrap();
+/// assert!(phrases[0].starts_with("the Browserslist defaults (versions with more than 0.5%"));
+/// ```
+pub fn describe<I, S>(queries: I, opts: &DescribeOpts) -> Result<Vec<String>, Error>
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = S>,
+{
+    describe_query(&join_queries(queries), opts)
+}
+
+fn describe_query(query: &str, opts: &DescribeOpts) -> Result<Vec<String>, Error> {
+    parse_browserslist_query(query)?
+        .1
+        .into_iter()
+        .enumerate()
+        .map(|(i, current)| {
+            if i == 0 && current.negated {
+                return Err(Error::NotAtFirst(current.raw.to_string()));
+            }
+
+            let mut phrase = describe_atom(&current.atom)?;
+            if opts.expand {
+                if let Some(queries) = expand_builtin(&current.atom) {
+                    let expanded = describe_query(&join_queries(queries), opts)?;
+                    phrase = format!("{} ({})", phrase, expanded.join(", "));
+                }
+            }
+
+            Ok(if current.negated {
+                format!("excluding {}", phrase)
+            } else if current.is_and {
+                format!("limited to {}", phrase)
+            } else {
+                phrase
+            })
+        })
+        .collect()
+}
+
+fn describe_atom(atom: &QueryAtom) -> Result<String, Error> {
+    let phrase = match atom {
+        QueryAtom::Last { count, major, name } => {
+            let kind = if *major { "major" } else { "released" };
+            let browser = match name {
+                Some(name) => browser_name(name),
+                None => String::from("every browser"),
+            };
+            if *count == 1 {
+                format!("the latest {} version of {}", kind, browser)
+            } else {
+                format!("the last {} {} versions of {}", count, kind, browser)
+            }
+        }
+        QueryAtom::Unreleased(Some(name)) => {
+            format!("unreleased versions of {}", browser_name(name))
+        }
+        QueryAtom::Unreleased(None) => String::from("unreleased versions of every browser"),
+        QueryAtom::Years(count) => {
+            if *count == 1.0 {
+                String::from("versions released in the last year")
+            } else {
+                format!("versions released in the last {} years", count)
+            }
+        }
+        QueryAtom::Since { year, month, day } => {
+            if *month == 1 && *day == 1 {
+                format!("versions released since {}", year)
+            } else {
+                format!("versions released since {}-{:02}-{:02}", year, month, day)
+            }
+        }
+        QueryAtom::Percentage {
+            comparator,
+            popularity,
+            stats,
+        } => {
+            let comparator = match comparator {
+                Comparator::Greater => "more than",
+                Comparator::GreaterOrEqual => "at least",
+                Comparator::Less => "less than",
+                Comparator::LessOrEqual => "at most",
+            };
+            let usage = match stats {
+                Stats::Global => String::from("global usage"),
+                Stats::Region(region) => format!("usage in {}", region_name(region)),
+            };
+            format!("versions with {} {}% {}", comparator, popularity, usage)
+        }
+        QueryAtom::Cover { coverage, stats } => {
+            let users = match stats {
+                Stats::Global => String::from("global users"),
+                Stats::Region(region) => format!("users in {}", region_name(region)),
+            };
+            format!(
+                "the most popular versions covering {}% of {}",
+                coverage, users
+            )
+        }
+        QueryAtom::Supports {
+            name,
+            without_prefix,
+        } => {
+            let prefix = if *without_prefix {
+                " without vendor prefix"
+            } else {
+                ""
+            };
+            format!("versions supporting the \"{}\" feature{}", name, prefix)
+        }
+        QueryAtom::Electron(range) => describe_range("Electron", range),
+        QueryAtom::Node(range) => describe_range("Node.js", range),
+        QueryAtom::Browser(name, range) => describe_range(&browser_name(name), range),
+        QueryAtom::FirefoxESR => String::from("the latest Firefox Extended Support Release"),
+        QueryAtom::OperaMini => String::from("Opera Mini"),
+        QueryAtom::CurrentNode => String::from("the Node.js version running this tool"),
+        QueryAtom::MaintainedNode => String::from("Node.js versions which are still maintained"),
+        QueryAtom::Phantom(is_later_version) => {
+            format!(
+                "PhantomJS {}",
+                if *is_later_version { "2.1" } else { "1.9" }
+            )
+        }
+        QueryAtom::BrowserslistConfig => {
+            String::from("browsers from the project's Browserslist configuration")
+        }
+        QueryAtom::Defaults => String::from("the Browserslist defaults"),
+        QueryAtom::Dead => String::from("dead browsers"),
+        QueryAtom::Unknown(query) => return Err(Error::UnknownQuery(query.to_string())),
+    };
+    Ok(phrase)
+}
+
+fn describe_range(browser: &str, range: &VersionRange) -> String {
+    match range {
+        VersionRange::Accurate(version) => format!("{} {}", browser, version),
+        VersionRange::Bounded(from, to) => format!("{} versions {} to {}", browser, from, to),
+        VersionRange::Unbounded(Comparator::GreaterOrEqual, "0") => {
+            format!("every version of {}", browser)
+        }
+        VersionRange::Unbounded(comparator, version) => match comparator {
+            Comparator::Greater => format!("{} versions newer than {}", browser, version),
+            Comparator::GreaterOrEqual => format!("{} {} and newer", browser, version),
+            Comparator::Less => format!("{} versions older than {}", browser, version),
+            Comparator::LessOrEqual => format!("{} {} and older", browser, version),
+        },
+    }
+}
+
+fn browser_name(name: &str) -> String {
+    let canonical = if name.eq_ignore_ascii_case("electron") {
+        "electron"
+    } else if name.eq_ignore_ascii_case("node") {
+        "node"
+    } else {
+        match get_browser_stat(name, false) {
+            Some((canonical, _)) => canonical,
+            None => return name.to_string(),
+        }
+    };
+    let display_name = match canonical {
+        "and_chr" => "Chrome for Android",
+        "and_ff" => "Firefox for Android",
+        "and_qq" => "QQ Browser",
+        "and_uc" => "UC Browser for Android",
+        "android" => "Android WebView",
+        "baidu" => "Baidu Browser",
+        "bb" => "BlackBerry Browser",
+        "chrome" => "Chrome",
+        "edge" => "Edge",
+        "electron" => "Electron",
+        "firefox" => "Firefox",
+        "ie" => "Internet Explorer",
+        "ie_mob" => "Internet Explorer Mobile",
+        "ios_saf" => "Safari on iOS",
+        "kaios" => "KaiOS Browser",
+        "node" => "Node.js",
+        "op_mini" => "Opera Mini",
+        "op_mob" => "Opera Mobile",
+        "opera" => "Opera",
+        "safari" => "Safari",
+        "samsung" => "Samsung Internet",
+        _ => canonical,
+    };
+    display_name.to_string()
+}
+
+fn region_name(region: &str) -> String {
+    let name = match region.to_ascii_lowercase().as_str() {
+        "alt-af" => "Africa",
+        "alt-an" => "Antarctica",
+        "alt-as" => "Asia",
+        "alt-eu" => "Europe",
+        "alt-na" => "North America",
+        "alt-oc" => "Oceania",
+        "alt-sa" => "South America",
+        "alt-ww" => "the world",
+        _ => return region.to_ascii_uppercase(),
+    };
+    name.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("last 2 versions", "the last 2 released versions of every browser"; "last versions")]
+    #[test_case("last 1 major version", "the latest major version of every browser"; "last major")]
+    #[test_case("last 3 ff versions", "the last 3 released versions of Firefox"; "last browser versions")]
+    #[test_case("last 2 node major versions", "the last 2 major versions of Node.js"; "last node")]
+    #[test_case("unreleased Chrome versions", "unreleased versions of Chrome"; "unreleased")]
+    #[test_case("last 1 year", "versions released in the last year"; "years")]
+    #[test_case("last 2.5 years", "versions released in the last 2.5 years"; "fractional years")]
+    #[test_case("since 2015", "versions released since 2015"; "since year")]
+    #[test_case("since 2017-03-10", "versions released since 2017-03-10"; "since date")]
+    #[test_case("> 0.5%", "versions with more than 0.5% global usage"; "percentage")]
+    #[test_case("<= 5% in alt-EU", "versions with at most 5% usage in Europe"; "percentage in continent")]
+    #[test_case(">= 1% in us", "versions with at least 1% usage in US"; "percentage in country")]
+    #[test_case("cover 99.5%", "the most popular versions covering 99.5% of global users"; "cover")]
+    #[test_case("supports es6-module", "versions supporting the \"es6-module\" feature"; "supports")]
+    #[test_case("supports css-appearance without prefix", "versions supporting the \"css-appearance\" feature without vendor prefix"; "supports without prefix")]
+    #[test_case("ie 11", "Internet Explorer 11"; "browser")]
+    #[test_case("iOS 12-14", "Safari on iOS versions 12 to 14"; "bounded range")]
+    #[test_case("chrome > 100", "Chrome versions newer than 100"; "greater")]
+    #[test_case("safari <= 13", "Safari 13 and older"; "less or equal")]
+    #[test_case("Baidu >= 0", "every version of Baidu Browser"; "every version")]
+    #[test_case("electron >= 20", "Electron 20 and newer"; "electron")]
+    #[test_case("node 16.0.0", "Node.js 16.0.0"; "node")]
+    #[test_case("yuru 1.0", "yuru 1.0"; "unknown browser")]
+    #[test_case("Firefox ESR", "the latest Firefox Extended Support Release"; "firefox esr")]
+    #[test_case("maintained node versions", "Node.js versions which are still maintained"; "maintained node")]
+    #[test_case("phantomjs 2.1", "PhantomJS 2.1"; "phantom")]
+    #[test_case("defaults", "the Browserslist defaults"; "defaults")]
+    fn single(query: &str, expected: &str) {
+        assert_eq!(describe([query], &DescribeOpts::new()).unwrap(), [expected]);
+    }
+
+    #[test]
+    fn combinators() {
+        assert_eq!(
+            describe(
+                ["ie 11, last 2 versions and > 1%, not dead"],
+                &DescribeOpts::new()
+            )
+            .unwrap(),
+            [
+                "Internet Explorer 11",
+                "the last 2 released versions of every browser",
+                "limited to versions with more than 1% global usage",
+                "excluding dead browsers",
+            ]
+        );
+    }
+
+    #[test]
+    fn expand() {
+        let phrases = describe(["defaults, not ie 11"], DescribeOpts::new().expand(true)).unwrap();
+        assert_eq!(
+            phrases,
+            [
+                "the Browserslist defaults (versions with more than 0.5% global usage, \
+                the last 2 released versions of every browser, \
+                the latest Firefox Extended Support Release, \
+                excluding dead browsers (every version of Baidu Browser, \
+                Internet Explorer 11 and older, Internet Explorer Mobile 11 and older, \
+                BlackBerry Browser 10 and older, Opera Mobile 12.1 and older, \
+                Samsung Internet 4))",
+                "excluding Internet Explorer 11",
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            describe(["not ie 11"], &DescribeOpts::new()),
+            Err(Error::NotAtFirst("not ie 11".to_string()))
+        );
+        assert!(describe(["ie 11,"], &DescribeOpts::new()).is_err());
+    }
+}
@@ -1,3 +1,4 @@
+use alloc::{borrow::ToOwned, string::String};
 use thiserror::Error;
 
 /// The errors may occur when querying with browserslist.
@@ -31,20 +32,51 @@ pub enum Error {
     #[error("unknown version '{1}' of browser '{0}'")]
     UnknownBrowserVersion(String, String),
 
+    /// Version string can't be parsed.
+    #[error("invalid version: '{0}'")]
+    InvalidVersion(String),
+
     /// Current environment doesn't support querying `current node`,
     /// for example, running this library on Non-Node.js platform or
     /// no Node.js installed.
     #[error("current environment for querying `current node` is not supported")]
     UnsupportedCurrentNode,
 
+    /// Current time is required by query but it isn't available,
+    /// for example, running this library without `std` and not setting [`Opts::now`](crate::Opts::now).
+    #[error("current time is required by query but it isn't provided")]
+    MissingCurrentTime,
+
+    /// Current time set by [`Opts::now`](crate::Opts::now) is out of range of date and time.
+    #[error("current time is out of range: {0}")]
+    CurrentTimeOutOfRange(i64),
+
     /// Unknown browser feature.
     #[error("unknown browser feature: '{0}'")]
     UnknownBrowserFeature(String),
 
+    /// Support of the given feature is unknown for the given browser,
+    /// for example, Can I Use doesn't have data of Node.js.
+    #[error("support of feature '{0}' is unknown for '{1}'")]
+    UnknownFeatureSupport(String, String),
+
     /// Unknown Can I Use region.
     #[error("unknown region: '{0}'")]
     UnknownRegion(String),
 
+    /// Browser can't be recognized from the given User-Agent string.
+    #[error("unknown user agent: '{0}'")]
+    UnknownUserAgent(String),
+
+    /// Query is longer than the limit set by [`Opts::max_query_length`](crate::Opts::max_query_length).
+    #[error("query is too long; at most {0} bytes are allowed")]
+    QueryTooLong(usize),
+
+    /// Query consists of more single queries than the limit set by
+    /// [`Opts::max_queries`](crate::Opts::max_queries).
+    #[error("too many queries; at most {0} queries are allowed")]
+    TooManyQueries(usize),
+
     /// Query can't be recognized.
     #[error("unknown browser query: '{0}'")]
     UnknownQuery(String),
@@ -0,0 +1,299 @@
+use crate::{
+    error::Error,
+    opts::Opts,
+    parser::QueryAtom,
+    queries::{self, Distrib},
+};
+use alloc::{string::ToString, vec::Vec};
+use core::fmt;
+use serde::Serialize;
+
+/// Edition of ECMAScript.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EsEdition {
+    /// ECMAScript 5.
+    Es5,
+    /// ECMAScript 2015, also known as ES6.
+    Es2015,
+    /// ECMAScript 2016.
+    Es2016,
+    /// ECMAScript 2017.
+    Es2017,
+    /// ECMAScript 2018.
+    Es2018,
+    /// ECMAScript 2019.
+    Es2019,
+    /// ECMAScript 2020.
+    Es2020,
+    /// ECMAScript 2021.
+    Es2021,
+    /// ECMAScript 2022.
+    Es2022,
+    /// ECMAScript 2023.
+    Es2023,
+}
+
+impl EsEdition {
+    const ALL: [EsEdition; 10] = [
+        EsEdition::Es5,
+        EsEdition::Es2015,
+        EsEdition::Es2016,
+        EsEdition::Es2017,
+        EsEdition::Es2018,
+        EsEdition::Es2019,
+        EsEdition::Es2020,
+        EsEdition::Es2021,
+        EsEdition::Es2022,
+        EsEdition::Es2023,
+    ];
+
+    /// Return features which mark support of this edition,
+    /// either from Can I Use or from MDN browser-compat-data with `mdn:` prefix,
+    /// in the same form as names in `supports` queries.
+    ///
+    /// A browser is considered to support an edition
+    /// only if it supports all marker features of this edition and all previous editions.
+    /// Like `supports` queries, partial support is counted as support.
+    pub fn marker_features(self) -> &'static [&'static str] {
+        match self {
+            EsEdition::Es5 => &["es5"],
+            EsEdition::Es2015 => &["es6"],
+            EsEdition::Es2016 => &["array-includes"],
+            EsEdition::Es2017 => &["async-functions", "object-values", "pad-start-end"],
+            EsEdition::Es2018 => &["promise-finally"],
+            EsEdition::Es2019 => &["array-flat"],
+            EsEdition::Es2020 => &["bigint", "es6-module-dynamic-import"],
+            EsEdition::Es2021 => &[
+                "mdn:javascript.builtins.Promise.any",
+                "mdn:javascript.builtins.String.replaceAll",
+            ],
+            EsEdition::Es2022 => &[
+                "mdn:javascript.builtins.Array.at",
+                "mdn:javascript.classes.private_class_fields",
+            ],
+            EsEdition::Es2023 => &["mdn:javascript.builtins.Array.findLast"],
+        }
+    }
+}
+
+impl fmt::Display for EsEdition {
+    /// Format like the `target` option of TypeScript and swc, such as `es5` and `es2015`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            EsEdition::Es5 => "es5",
+            EsEdition::Es2015 => "es2015",
+            EsEdition::Es2016 => "es2016",
+            EsEdition::Es2017 => "es2017",
+            EsEdition::Es2018 => "es2018",
+            EsEdition::Es2019 => "es2019",
+            EsEdition::Es2020 => "es2020",
+            EsEdition::Es2021 => "es2021",
+            EsEdition::Es2022 => "es2022",
+            EsEdition::Es2023 => "es2023",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Result of [`es_edition`].
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EsEditionSupport {
+    edition: Option<EsEdition>,
+    blocking_features: Vec<BlockingFeature>,
+}
+
+impl EsEditionSupport {
+    /// Return the highest edition supported by all browsers,
+    /// or `None` if even ES5 isn't supported.
+    #[inline]
+    pub fn edition(&self) -> Option<EsEdition> {
+        self.edition
+    }
+
+    /// Return marker features of the next edition which aren't supported by some browsers.
+    ///
+    /// It's empty if the highest known edition is supported.
+    #[inline]
+    pub fn blocking_features(&self) -> &[BlockingFeature] {
+        &self.blocking_features
+    }
+}
+
+/// Feature which prevents browsers from supporting an edition.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct BlockingFeature {
+    feature: &'static str,
+    browsers: Vec<Distrib>,
+}
+
+impl BlockingFeature {
+    /// Return name of the marker feature, such as `es6` or `mdn:javascript.builtins.Array.at`.
+    #[inline]
+    pub fn feature(&self) -> &'static str {
+        self.feature
+    }
+
+    /// Return browsers which don't support this feature.
+    ///
+    /// If the feature isn't found in bundled data,
+    /// none of browsers is known to support it, so all of them are returned.
+    #[inline]
+    pub fn browsers(&self) -> &[Distrib] {
+        &self.browsers
+    }
+}
+
+/// Find the highest ECMAScript edition which all of the given browsers support,
+/// according to [marker features](EsEdition::marker_features).
+///
+/// Node.js versions are checked against MDN browser-compat-data.
+/// Can I Use doesn't have data of Node.js, so [`Error::UnknownFeatureSupport`] is returned
+/// if Node.js versions are given while a marker feature comes from Can I Use.
+///
+/// ```
+/// use browserslist::{es_edition, resolve, EsEdition, Opts};
+///
+/// let distribs = resolve(["ie 11, chrome 100"], &Opts::new()).unwrap();
+/// let support = es_edition(&distribs).unwrap();
+///
+/// assert_eq!(support.edition(), Some(EsEdition::Es5));
+/// assert_eq!(support.edition().unwrap().to_string(), "es5");
+///
+/// let blocking = &support.blocking_features()[0];
+/// assert_eq!(blocking.feature(), "es6");
+/// assert_eq!(blocking.browsers()[0].to_string(), "ie 11");
+/// ```
+pub fn es_edition(distribs: &[Distrib]) -> Result<EsEditionSupport, Error> {
+    let mut edition = None;
+    for next in EsEdition::ALL {
+        let mut blocking_features = Vec::new();
+        for feature in next.marker_features() {
+            if !feature.starts_with("mdn:") {
+                if let Some(node) = distribs.iter().find(|distrib| distrib.is_node()) {
+                    return Err(Error::UnknownFeatureSupport(
+                        feature.to_string(),
+                        node.to_string(),
+                    ));
+                }
+            }
+
+            let supported = queries::query(
+                QueryAtom::Supports {
+                    name: feature,
+                    without_prefix: false,
+                },
+                &Opts::new(),
+            )?;
+            let browsers = distribs
+                .iter()
+                .filter(|distrib| !supported.contains(distrib))
+                .copied()
+                .collect::<Vec<_>>();
+            if !browsers.is_empty() {
+                blocking_features.push(BlockingFeature { feature, browsers });
+            }
+        }
+
+        if !blocking_features.is_empty() {
+            return Ok(EsEditionSupport {
+                edition,
+                blocking_features,
+            });
+        }
+        edition = Some(next);
+    }
+
+    Ok(EsEditionSupport {
+        edition,
+        blocking_features: Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{opts::Opts, resolve};
+
+    #[test]
+    fn ordering_and_display() {
+        assert!(EsEdition::ALL.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(EsEdition::Es2015.to_string(), "es2015");
+        assert_eq!(
+            serde_json::to_string(&EsEdition::Es2023).unwrap(),
+            "\"es2023\""
+        );
+    }
+
+    #[test]
+    fn blocked_by_old_browser() {
+        let distribs = resolve(["ie 11, chrome 100"], &Opts::new()).unwrap();
+        let support = es_edition(&distribs).unwrap();
+        assert_eq!(support.edition(), Some(EsEdition::Es5));
+        assert_eq!(support.blocking_features().len(), 1);
+        assert_eq!(support.blocking_features()[0].feature(), "es6");
+        assert_eq!(
+            support.blocking_features()[0]
+                .browsers()
+                .iter()
+                .map(|distrib| distrib.to_string())
+                .collect::<Vec<_>>(),
+            ["ie 11"]
+        );
+    }
+
+    #[test]
+    fn no_edition() {
+        let distribs = resolve(["op_mini all"], &Opts::new()).unwrap();
+        let support = es_edition(&distribs).unwrap();
+        assert_eq!(support.edition(), None);
+        assert_eq!(support.blocking_features()[0].feature(), "es5");
+    }
+
+    #[test]
+    fn modern_browsers() {
+        let distribs = resolve(["chrome 120, firefox 120"], &Opts::new()).unwrap();
+        let support = es_edition(&distribs).unwrap();
+        assert_eq!(support.edition(), Some(EsEdition::Es2023));
+        assert!(support.blocking_features().is_empty());
+    }
+
+    #[test]
+    fn blocked_by_mdn_feature() {
+        let distribs = resolve(["chrome 100, firefox 100"], &Opts::new()).unwrap();
+        let support = es_edition(&distribs).unwrap();
+        assert_eq!(support.edition(), Some(EsEdition::Es2022));
+        assert_eq!(
+            support.blocking_features()[0].feature(),
+            "mdn:javascript.builtins.Array.findLast"
+        );
+        assert_eq!(
+            support.blocking_features()[0]
+                .browsers()
+                .iter()
+                .map(|distrib| distrib.to_string())
+                .collect::<Vec<_>>(),
+            ["firefox 100"]
+        );
+    }
+
+    #[test]
+    fn node() {
+        let distribs = resolve(["chrome 100, node 10.0.0"], &Opts::new()).unwrap();
+        assert_eq!(
+            es_edition(&distribs),
+            Err(Error::UnknownFeatureSupport(
+                "es5".to_string(),
+                "node 10.0.0".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let support = es_edition(&[]).unwrap();
+        assert_eq!(support.edition(), Some(EsEdition::Es2023));
+        assert!(support.blocking_features().is_empty());
+    }
+}
@@ -0,0 +1,202 @@
+use crate::{
+    coverage::{custom_browser_name, usage_data, Stats},
+    error::Error,
+    queries::Distrib,
+};
+use alloc::vec::Vec;
+use serde::Serialize;
+
+/// Result of [`excluded_audience`].
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ExcludedAudience<'a> {
+    versions: Vec<ExcludedVersion<'a>>,
+}
+
+impl<'a> ExcludedAudience<'a> {
+    /// Return excluded browser versions, sorted by usage in descending order.
+    #[inline]
+    pub fn versions(&self) -> &[ExcludedVersion<'a>] {
+        &self.versions
+    }
+
+    /// Return total share of excluded users (in percentage).
+    pub fn total(&self) -> f32 {
+        self.versions
+            .last()
+            .map(|version| version.cumulative_usage)
+            .unwrap_or_default()
+    }
+}
+
+/// Browser version which isn't in the resolution but has users.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExcludedVersion<'a> {
+    name: &'a str,
+    version: &'a str,
+    usage: f32,
+    cumulative_usage: f32,
+}
+
+impl<'a> ExcludedVersion<'a> {
+    /// Return browser name.
+    #[inline]
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Return browser version.
+    #[inline]
+    pub fn version(&self) -> &'a str {
+        self.version
+    }
+
+    /// Return usage (in percentage) of this version.
+    #[inline]
+    pub fn usage(&self) -> f32 {
+        self.usage
+    }
+
+    /// Return total usage (in percentage) of this version and all versions before it.
+    #[inline]
+    pub fn cumulative_usage(&self) -> f32 {
+        self.cumulative_usage
+    }
+}
+
+/// Find out which browser versions with users aren't in the given browsers,
+/// that is, who are lost with a query.
+///
+/// Versions without usage are omitted.
+///
+/// ```
+/// use browserslist::{excluded_audience, resolve, Opts, Stats};
+///
+/// let distribs = resolve(["> 1%"], &Opts::new()).unwrap();
+/// let excluded = excluded_audience(&distribs, Stats::Global).unwrap();
+///
+/// assert!(excluded.versions().iter().all(|version| version.usage() <= 1.0));
+/// assert!(excluded.total() > 0.0);
+/// ```
+pub fn excluded_audience<'a>(
+    distribs: &[Distrib],
+    stats: Stats<'a>,
+) -> Result<ExcludedAudience<'a>, Error> {
+    let is_included = |name: &str, version: &str| {
+        distribs
+            .iter()
+            .any(|distrib| distrib.name() == name && distrib.version() == version)
+    };
+
+    let mut versions = match stats {
+        Stats::Custom(stats) => stats
+            .iter()
+            .flat_map(|(name, versions)| {
+                let name = custom_browser_name(name).unwrap_or(name);
+                versions
+                    .iter()
+                    .map(move |(version, usage)| (name, &**version, *usage))
+            })
+            .filter(|(name, version, usage)| *usage > 0.0 && !is_included(name, version))
+            .collect::<Vec<_>>(),
+        _ => usage_data(stats)?
+            .iter()
+            .filter(|(name, version, usage)| *usage > 0.0 && !is_included(name, version))
+            .map(|(name, version, usage)| (*name, *version, *usage))
+            .collect(),
+    };
+    versions.sort_by(|(_, _, a), (_, _, b)| b.total_cmp(a));
+
+    let mut cumulative_usage = 0.0;
+    let versions = versions
+        .into_iter()
+        .map(|(name, version, usage)| {
+            cumulative_usage += usage;
+            ExcludedVersion {
+                name,
+                version,
+                usage,
+                cumulative_usage,
+            }
+        })
+        .collect();
+
+    Ok(ExcludedAudience { versions })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coverage::CustomStats, opts::Opts, resolve};
+    use alloc::string::String;
+
+    #[test]
+    fn global() {
+        let distribs = resolve(["last 2 versions"], &Opts::new()).unwrap();
+        let excluded = excluded_audience(&distribs, Stats::Global).unwrap();
+        let versions = excluded.versions();
+        assert!(!versions.is_empty());
+        assert!(versions
+            .windows(2)
+            .all(|pair| pair[0].usage() >= pair[1].usage()));
+        assert!(versions.iter().all(|version| !distribs
+            .iter()
+            .any(|distrib| distrib.name() == version.name()
+                && distrib.version() == version.version())));
+
+        let all = versions.iter().map(|version| version.usage()).sum::<f32>();
+        assert!((excluded.total() - all).abs() < 1e-3);
+    }
+
+    #[test]
+    fn region() {
+        let distribs = resolve(["> 1% in US"], &Opts::new()).unwrap();
+        let excluded = excluded_audience(&distribs, Stats::Region("us")).unwrap();
+        assert!(excluded
+            .versions()
+            .iter()
+            .all(|version| version.usage() <= 1.0));
+        assert_eq!(
+            excluded_audience(&distribs, Stats::Region("XX")),
+            Err(Error::UnknownRegion(String::from("XX")))
+        );
+    }
+
+    #[test]
+    fn custom() {
+        let stats: CustomStats = serde_json::from_str(
+            r#"{ "Chrome": { "100": 10.5, "99": 2 }, "ie": { "11": 5, "10": 0 }, "unknown": { "1": 3 } }"#,
+        )
+        .unwrap();
+        let distribs = resolve(["chrome 100"], &Opts::new()).unwrap();
+        let excluded = excluded_audience(&distribs, Stats::Custom(&stats)).unwrap();
+        let versions = excluded
+            .versions()
+            .iter()
+            .map(|version| {
+                (
+                    version.name(),
+                    version.version(),
+                    version.cumulative_usage(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            versions,
+            [
+                ("ie", "11", 5.0),
+                ("unknown", "1", 8.0),
+                ("chrome", "99", 10.0)
+            ]
+        );
+        assert_eq!(excluded.total(), 10.0);
+    }
+
+    #[test]
+    fn nothing_excluded() {
+        let stats = CustomStats::new();
+        let excluded = excluded_audience(&[], Stats::Custom(&stats)).unwrap();
+        assert!(excluded.versions().is_empty());
+        assert_eq!(excluded.total(), 0.0);
+    }
+}
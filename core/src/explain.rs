@@ -0,0 +1,335 @@
+use crate::{
+    compare_distribs,
+    error::Error,
+    evaluate_with, join_queries,
+    opts::Opts,
+    parse_queries,
+    parser::{parse_browserslist_query, QueryAtom, SingleQuery},
+    queries::{self, Expansion},
+    Distrib, EvaluationHook,
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+use serde::Serialize;
+
+/// Result of [`resolve_explained`], which is a tree of evaluation steps.
+///
+/// It can be displayed as a readable tree:
+///
+/// ```text
+/// ie 11, not dead => 0 results
+/// ├── ie 11 => 1 result, 1 in total
+/// └── not dead => 17 results, 0 in total
+///     ├── Baidu >= 0 => 1 result, 1 in total
+///     └── ...
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Explanation {
+    query: String,
+    steps: Vec<Step>,
+    distribs: Vec<Distrib>,
+}
+
+impl Explanation {
+    /// Return the joined query.
+    #[inline]
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Return evaluation steps, one for each clause.
+    #[inline]
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    /// Return the final result, which is the same as [`resolve`](crate::resolve).
+    #[inline]
+    pub fn distribs(&self) -> &[Distrib] {
+        &self.distribs
+    }
+}
+
+/// How a clause is combined with results of previous clauses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Combinator {
+    /// Results of the clause are added, with `,` or `or`.
+    Or,
+    /// Only results which the clause also produces are kept, with `and`.
+    And,
+    /// Results of the clause are removed, with `not`.
+    Not,
+}
+
+/// Evaluation of a single clause.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Step {
+    raw: String,
+    atom: String,
+    combinator: Combinator,
+    produced: Vec<Distrib>,
+    running: Vec<Distrib>,
+    expansion: Option<Explanation>,
+}
+
+impl Step {
+    /// Return text of the clause.
+    #[inline]
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    /// Return debug representation of the query atom which the clause is parsed to.
+    ///
+    /// Its format isn't stable, so it's only for reading.
+    #[inline]
+    pub fn atom(&self) -> &str {
+        &self.atom
+    }
+
+    /// Return how the clause is combined with previous clauses.
+    #[inline]
+    pub fn combinator(&self) -> Combinator {
+        self.combinator
+    }
+
+    /// Return results produced by the clause itself.
+    #[inline]
+    pub fn produced(&self) -> &[Distrib] {
+        &self.produced
+    }
+
+    /// Return results after combining with previous clauses.
+    #[inline]
+    pub fn running(&self) -> &[Distrib] {
+        &self.running
+    }
+
+    /// Return evaluation of queries which the clause expands to,
+    /// for clauses like `defaults`, `dead` and `browserslist config`.
+    #[inline]
+    pub fn expansion(&self) -> Option<&Explanation> {
+        self.expansion.as_ref()
+    }
+}
+
+/// Resolve browserslist queries like [`resolve`](crate::resolve),
+/// and record how each clause is evaluated.
+///
+/// ```
+/// use browserslist::{resolve_explained, Combinator, Opts};
+///
+/// let explanation = resolve_explained(["ie >= 10, not dead"], &Opts::new()).unwrap();
+/// assert!(explanation.distribs().is_empty());
+///
+/// let steps = explanation.steps();
+/// assert_eq!(steps[0].raw(), "ie >= 10");
+/// assert_eq!(steps[0].running().len(), 2);
+/// assert_eq!(steps[1].combinator(), Combinator::Not);
+/// assert_eq!(steps[1].expansion().unwrap().steps()[1].raw(), "ie <= 11");
+///
+/// println!("{}", explanation);
+/// ```
+pub fn resolve_explained<I, S>(queries: I, opts: &Opts) -> Result<Explanation, Error>
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = S>,
+{
+    let query = join_queries(queries);
+    let single_queries = parse_queries(&query, opts)?;
+    explain(query.clone(), single_queries, opts)
+}
+
+fn explain(
+    query: String,
+    single_queries: Vec<SingleQuery>,
+    opts: &Opts,
+) -> Result<Explanation, Error> {
+    let mut explainer = Explainer::default();
+    let distribs = evaluate_with(single_queries, opts, &mut explainer)?;
+    Ok(Explanation {
+        query,
+        steps: explainer.steps,
+        distribs,
+    })
+}
+
+#[derive(Default)]
+struct Explainer {
+    steps: Vec<Step>,
+    /// Debug representation and expansion of the clause being evaluated.
+    current: Option<(String, Option<Explanation>)>,
+}
+
+impl EvaluationHook for Explainer {
+    fn produce(&mut self, atom: QueryAtom, opts: &Opts) -> Result<Vec<Distrib>, Error> {
+        let debug = format!("{:?}", atom);
+        let expansion = match queries::expand(&atom, opts)? {
+            Some(Expansion::Builtin(queries)) => {
+                let query = join_queries(queries);
+                let single_queries = parse_browserslist_query(&query)?.1;
+                Some(explain(query.clone(), single_queries, opts)?)
+            }
+            Some(Expansion::Config(queries)) => {
+                let query = join_queries(queries);
+                let single_queries = parse_queries(&query, opts)?;
+                Some(explain(query.clone(), single_queries, opts)?)
+            }
+            None => None,
+        };
+        let produced = match &expansion {
+            Some(expansion) => expansion.distribs.clone(),
+            None => queries::query(atom, opts)?,
+        };
+        self.current = Some((debug, expansion));
+        Ok(produced)
+    }
+
+    fn combined(
+        &mut self,
+        _index: usize,
+        raw: &str,
+        combinator: Combinator,
+        mut produced: Vec<Distrib>,
+        running: &[Distrib],
+    ) {
+        let (atom, expansion) = self.current.take().unwrap_or_default();
+        sort_and_dedup(&mut produced);
+        let mut running = running.to_vec();
+        sort_and_dedup(&mut running);
+        self.steps.push(Step {
+            raw: raw.to_string(),
+            atom,
+            combinator,
+            produced,
+            running,
+            expansion,
+        });
+    }
+}
+
+fn sort_and_dedup(distribs: &mut Vec<Distrib>) {
+    distribs.sort_by(compare_distribs);
+    distribs.dedup();
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} => {}", self.query, Count(self.distribs.len()))?;
+        self.fmt_steps(f, "")
+    }
+}
+
+impl Explanation {
+    fn fmt_steps(&self, f: &mut fmt::Formatter<'_>, indent: &str) -> fmt::Result {
+        for (i, step) in self.steps.iter().enumerate() {
+            let is_last = i + 1 == self.steps.len();
+            let branch = if is_last { "└── " } else { "├── " };
+            // `not` is a part of clause, but `and` isn't
+            let and = if step.combinator == Combinator::And {
+                "and "
+            } else {
+                ""
+            };
+            write!(
+                f,
+                "{}{}{}{} => {}",
+                indent,
+                branch,
+                and,
+                step.raw,
+                Count(step.produced.len())
+            )?;
+            writeln!(f, ", {} in total", step.running.len())?;
+            if let Some(expansion) = &step.expansion {
+                let indent = format!("{}{}", indent, if is_last { "    " } else { "│   " });
+                expansion.fmt_steps(f, &indent)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+struct Count(usize);
+
+impl fmt::Display for Count {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0 == 1 {
+            write!(f, "1 result")
+        } else {
+            write!(f, "{} results", self.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resolve;
+    use test_case::test_case;
+
+    #[test_case("defaults"; "defaults")]
+    #[test_case("> 1%, last 2 versions, not dead"; "or and not")]
+    #[test_case("last 2 versions and > 0.5%, ie 11"; "and")]
+    #[test_case("browserslist config, ie 11"; "config")]
+    #[test_case("node >= 16, electron 20"; "node and electron")]
+    fn same_as_resolve(query: &str) {
+        let opts = Opts::new();
+        let explanation = resolve_explained([query], &opts).unwrap();
+        let expected = resolve([query], &opts).unwrap();
+        assert_eq!(explanation.distribs(), expected);
+        assert_eq!(explanation.steps().last().unwrap().running(), expected);
+    }
+
+    #[test]
+    fn nested() {
+        let explanation = resolve_explained(["defaults"], &Opts::new()).unwrap();
+        let defaults = explanation.steps()[0].expansion().unwrap();
+        assert_eq!(
+            defaults.query(),
+            "> 0.5%, last 2 versions, Firefox ESR, not dead"
+        );
+        let dead = &defaults.steps()[3];
+        assert_eq!(dead.combinator(), Combinator::Not);
+        assert_eq!(dead.expansion().unwrap().steps().len(), 6);
+        assert!(dead.expansion().unwrap().steps()[0].expansion().is_none());
+        assert_eq!(dead.atom(), "Dead");
+    }
+
+    #[test]
+    fn display() {
+        let explanation =
+            resolve_explained(["ie 11 and ie >= 10, not dead"], &Opts::new()).unwrap();
+        assert_eq!(
+            explanation.to_string(),
+            "ie 11 and ie >= 10, not dead => 0 results
+├── ie 11 => 1 result, 1 in total
+├── and ie >= 10 => 2 results, 1 in total
+└── not dead => 17 results, 0 in total
+    ├── Baidu >= 0 => 1 result, 1 in total
+    ├── ie <= 11 => 7 results, 8 in total
+    ├── ie_mob <= 11 => 2 results, 10 in total
+    ├── bb <= 10 => 2 results, 12 in total
+    ├── op_mob <= 12.1 => 4 results, 16 in total
+    └── samsung 4 => 1 result, 17 in total
+"
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            resolve_explained(["not ie 11"], &Opts::new()),
+            Err(Error::NotAtFirst("not ie 11".to_string()))
+        );
+        assert_eq!(
+            resolve_explained(["ie 11, yuru 1.0"], &Opts::new()),
+            Err(Error::BrowserNotFound("yuru".to_string()))
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{
+    coverage::{custom_browser_name, usage_data, Stats},
+    data::caniuse::features::get_feature_stat,
+    error::Error,
+};
+use alloc::{collections::BTreeMap, string::ToString};
+use serde::Serialize;
+
+/// Result of [`feature_usage`].
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureUsage {
+    total: SupportShare,
+    by_browser: BTreeMap<&'static str, SupportShare>,
+}
+
+impl FeatureUsage {
+    /// Return share of all users.
+    #[inline]
+    pub fn total(&self) -> &SupportShare {
+        &self.total
+    }
+
+    /// Return share of users of each browser.
+    #[inline]
+    pub fn by_browser(&self) -> &BTreeMap<&'static str, SupportShare> {
+        &self.by_browser
+    }
+}
+
+/// Share of users (in percentage) by support level of a feature.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+pub struct SupportShare {
+    full: f32,
+    partial: f32,
+    unsupported: f32,
+}
+
+impl SupportShare {
+    /// Return share of users whose browsers fully support the feature.
+    #[inline]
+    pub fn full(&self) -> f32 {
+        self.full
+    }
+
+    /// Return share of users whose browsers partially support the feature.
+    #[inline]
+    pub fn partial(&self) -> f32 {
+        self.partial
+    }
+
+    /// Return share of users whose browsers don't support the feature.
+    #[inline]
+    pub fn unsupported(&self) -> f32 {
+        self.unsupported
+    }
+
+    fn add(&mut self, partial: Option<bool>, usage: f32) {
+        match partial {
+            Some(false) => self.full += usage,
+            Some(true) => self.partial += usage,
+            None => self.unsupported += usage,
+        }
+    }
+}
+
+/// Calculate what share of users can use a Can I Use feature.
+///
+/// Versions supporting the feature only with prefix are counted as supported.
+/// With custom statistics, browsers unknown to Can I Use are ignored.
+///
+/// ```
+/// use browserslist::{feature_usage, Stats};
+///
+/// let usage = feature_usage("es6", Stats::Global).unwrap();
+/// assert!(usage.total().full() > 0.0);
+/// assert!(usage.by_browser()["ie"].full() == 0.0);
+///
+/// let in_us = feature_usage("es6", Stats::Region("US")).unwrap();
+/// assert!(in_us.total().full() > 0.0);
+/// ```
+pub fn feature_usage(feature: &str, stats: Stats) -> Result<FeatureUsage, Error> {
+    let stat = get_feature_stat(feature)
+        .ok_or_else(|| Error::UnknownBrowserFeature(feature.to_string()))?;
+    let support_of = |name: &str, version: &str| {
+        stat.iter()
+            .find(|(n, v, ..)| *n == name && *v == version)
+            .map(|(.., partial)| *partial)
+    };
+
+    let mut usage = FeatureUsage::default();
+    let mut add = |name: &'static str, version: &str, share: f32| {
+        let partial = support_of(name, version);
+        usage.total.add(partial, share);
+        usage
+            .by_browser
+            .entry(name)
+            .or_default()
+            .add(partial, share);
+    };
+    match stats {
+        Stats::Custom(stats) => stats
+            .iter()
+            .filter_map(|(name, versions)| custom_browser_name(name).map(|name| (name, versions)))
+            .for_each(|(name, versions)| {
+                versions
+                    .iter()
+                    .for_each(|(version, share)| add(name, version, *share))
+            }),
+        _ => usage_data(stats)?
+            .iter()
+            .for_each(|(name, version, share)| add(name, version, *share)),
+    }
+
+    Ok(usage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        coverage::{coverage, CustomStats},
+        opts::Opts,
+        resolve,
+    };
+    use alloc::string::String;
+
+    #[test]
+    fn global() {
+        let usage = feature_usage("css-appearance", Stats::Global).unwrap();
+        let supported = resolve(["supports css-appearance"], &Opts::new()).unwrap();
+        let expected = coverage(&supported, Stats::Global).unwrap().total();
+        let total = usage.total();
+        assert!(total.partial() > 0.0);
+        assert!((total.full() + total.partial() - expected).abs() < 1e-3);
+
+        let sum = |share: &SupportShare| share.full() + share.partial() + share.unsupported();
+        let by_browser = usage.by_browser().values().map(sum).sum::<f32>();
+        assert!((sum(total) - by_browser).abs() < 1e-3);
+    }
+
+    #[test]
+    fn region() {
+        let usage = feature_usage("es6", Stats::Region("us")).unwrap();
+        assert_eq!(Ok(usage.clone()), feature_usage("es6", Stats::Region("US")));
+        assert_eq!(usage.by_browser()["ie"].full(), 0.0);
+    }
+
+    #[test]
+    fn custom() {
+        let stats: CustomStats = serde_json::from_str(
+            r#"{ "Chrome": { "100": 10.5, "4": 1 }, "ie": { "11": 5 }, "unknown": { "1": 3 } }"#,
+        )
+        .unwrap();
+        let usage = feature_usage("es6", Stats::Custom(&stats)).unwrap();
+        assert_eq!(usage.total().full(), 10.5);
+        assert_eq!(usage.total().partial(), 0.0);
+        assert_eq!(usage.total().unsupported(), 6.0);
+        assert_eq!(usage.by_browser()["chrome"].unsupported(), 1.0);
+        assert_eq!(usage.by_browser()["ie"].unsupported(), 5.0);
+        assert!(!usage.by_browser().contains_key("unknown"));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            feature_usage("xxxyyyzzz", Stats::Global),
+            Err(Error::UnknownBrowserFeature(String::from("xxxyyyzzz")))
+        );
+        assert_eq!(
+            feature_usage("es6", Stats::Region("XX")),
+            Err(Error::UnknownRegion(String::from("XX")))
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use crate::data::{
+    caniuse::{get_browser_stat, CANIUSE_BROWSERS},
+    electron::ELECTRON_VERSIONS,
+    node::NODE_VERSIONS,
+    Lazy,
+};
+use alloc::collections::{BTreeMap, BTreeSet};
+use core::cmp::Ordering;
+
+/// Versions of each browser (and `node`) which queries can produce from bundled data.
+///
+/// Versions of mobile browsers mapped to desktop ones (with `mobile_to_desktop` option)
+/// and Chrome versions of Electron are included.
+static BUNDLED: Lazy<BTreeMap<&'static str, BTreeSet<&'static str>>> = Lazy::new(|| {
+    let mut bundled = CANIUSE_BROWSERS
+        .keys()
+        .map(|name| {
+            let versions = [false, true]
+                .into_iter()
+                .filter_map(|mobile_to_desktop| get_browser_stat(name, mobile_to_desktop))
+                .filter(|(canonical, _)| canonical == name)
+                .flat_map(|(_, stat)| stat.version_list.iter().map(|version| version.version))
+                .collect::<BTreeSet<_>>();
+            (*name, versions)
+        })
+        .collect::<BTreeMap<_, _>>();
+    if let Some(chrome) = bundled.get_mut("chrome") {
+        chrome.extend(ELECTRON_VERSIONS.iter().map(|(_, version)| *version));
+    }
+    bundled.insert("node", NODE_VERSIONS.iter().copied().collect());
+    bundled
+});
+
+/// Find browser name (or `node`) from bundled data.
+pub(crate) fn find_bundled_name(name: &str) -> Option<&'static str> {
+    BUNDLED.get_key_value(name).map(|(name, _)| *name)
+}
+
+/// Find browser name (or `node`) and its version from bundled data.
+pub(crate) fn find_bundled(name: &str, version: &str) -> Option<(&'static str, &'static str)> {
+    let (name, versions) = BUNDLED.get_key_value(name)?;
+    versions.get(version).map(|version| (*name, *version))
+}
+
+/// Sorted browser names (and `node`) with versions from bundled data, for searching in const context.
+///
+/// Mobile browsers have all versions of their desktop browsers,
+/// so it's a superset of [`BUNDLED`].
+const BUNDLED_TABLE: &[(&str, &str)] = include!(concat!(env!("OUT_DIR"), "/bundled-distribs.rs"));
+
+/// Check if browser name (or `node`) and its version are from bundled data in const context.
+pub(crate) const fn is_bundled(name: &str, version: &str) -> bool {
+    let (mut low, mut high) = (0, BUNDLED_TABLE.len());
+    while low < high {
+        let mid = (low + high) / 2;
+        let (mid_name, mid_version) = BUNDLED_TABLE[mid];
+        let ordering = match compare_bytes(mid_name.as_bytes(), name.as_bytes()) {
+            Ordering::Equal => compare_bytes(mid_version.as_bytes(), version.as_bytes()),
+            ordering => ordering,
+        };
+        match ordering {
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+            Ordering::Equal => return true,
+        }
+    }
+    false
+}
+
+/// Compare byte strings like `Ord` of `[u8]`, which isn't available in const context.
+const fn compare_bytes(a: &[u8], b: &[u8]) -> Ordering {
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] < b[i] {
+            return Ordering::Less;
+        } else if a[i] > b[i] {
+            return Ordering::Greater;
+        }
+        i += 1;
+    }
+    if a.len() < b.len() {
+        Ordering::Less
+    } else if a.len() > b.len() {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// Get a `'static` string which has same content of the given Node.js version.
+///
+/// Versions from bundled data are reused directly.
+/// Other versions (for example, version of current Node.js) are leaked once,
+/// then reused for later calls, so memory usage is bounded by the number of distinct strings.
+///
+/// Current Node.js version is only available with `std`, so is this.
+#[cfg(feature = "std")]
+pub(crate) fn intern(version: &str) -> &'static str {
+    find_bundled("node", version)
+        .map(|(_, version)| version)
+        .unwrap_or_else(|| intern_dynamic(version))
+}
+
+#[cfg(feature = "std")]
+fn intern_dynamic(s: &str) -> &'static str {
+    use crate::data::Lazy;
+    use ahash::AHashSet;
+    use alloc::boxed::Box;
+    use std::sync::Mutex;
+
+    static STRINGS: Lazy<Mutex<AHashSet<&'static str>>> = Lazy::new(Default::default);
+
+    let mut strings = STRINGS.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(interned) = strings.get(s) {
+        interned
+    } else {
+        let interned: &'static str = Box::leak(s.into());
+        strings.insert(interned);
+        interned
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::queries::Distrib;
+    use alloc::string::{String, ToString};
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reuse_interned() {
+        let a = intern(&String::from("18.0.0-nightly"));
+        let b = intern(&String::from("18.0.0-nightly"));
+        assert_eq!(a, "18.0.0-nightly");
+        assert!(core::ptr::eq(a, b));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reuse_bundled() {
+        let version = NODE_VERSIONS[0];
+        assert!(core::ptr::eq(intern(&String::from(version)), version));
+    }
+
+    #[test]
+    fn bundled() {
+        let version = CANIUSE_BROWSERS.get("ie").unwrap().version_list[0].version;
+        let (name, found) = find_bundled(&String::from("ie"), &String::from(version)).unwrap();
+        assert_eq!(name, "ie");
+        assert!(core::ptr::eq(found, version));
+        assert!(find_bundled("and_chr", "119").is_some());
+        assert!(find_bundled("node", "18.0.0").is_some());
+        assert_eq!(find_bundled("ie", "18.0.0"), None);
+        assert_eq!(find_bundled("yuru", "1.0"), None);
+        assert_eq!(find_bundled_name("node"), Some("node"));
+        assert_eq!(find_bundled_name("yuru"), None);
+    }
+
+    #[test]
+    fn bundled_table() {
+        assert!(BUNDLED_TABLE.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(BUNDLED
+            .iter()
+            .all(|(name, versions)| versions.iter().all(|version| is_bundled(name, version))));
+        assert!(is_bundled("and_chr", "100"));
+        assert!(!is_bundled("ie", "18.0.0"));
+        assert!(!is_bundled("yuru", "1.0"));
+        assert!(!is_bundled("", ""));
+    }
+
+    #[test]
+    fn from_static() {
+        assert_eq!(Distrib::__from_static("ie", "11").to_string(), "ie 11");
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_static_unknown() {
+        Distrib::__from_static("yuru", "1.0");
+    }
+}
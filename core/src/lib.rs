@@ -0,0 +1,248 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::float_cmp)]
+#![deny(clippy::if_not_else)]
+#![deny(clippy::needless_borrow)]
+#![deny(clippy::unimplemented)]
+#![warn(missing_docs)]
+
+//! Implementation of [**browserslist-rs**](https://docs.rs/browserslist-rs).
+//!
+//! Use `browserslist-rs` instead of depending on this crate directly.
+
+extern crate alloc;
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::cmp::Ordering;
+use parser::{parse_browserslist_query, QueryAtom, SingleQuery};
+pub use {
+    coverage::{coverage, Coverage, CustomStats, Stats},
+    describe::{describe, DescribeOpts},
+    error::Error,
+    es_edition::{es_edition, BlockingFeature, EsEdition, EsEditionSupport},
+    excluded::{excluded_audience, ExcludedAudience, ExcludedVersion},
+    explain::{resolve_explained, Combinator, Explanation, Step},
+    feature_usage::{feature_usage, FeatureUsage, SupportShare},
+    opts::Opts,
+    prefix::{prefix_requirements, PrefixRequirements, PrefixedTarget},
+    provenance::{resolve_with_provenance, Clause, ExcludedDistrib, IncludedDistrib, Provenance},
+    queries::Distrib,
+    targets::{babel::BabelTargets, esbuild::EsbuildTargets, lightningcss::LightningCssTargets},
+    timeline::{feature_timeline, FeatureTimeline, FirstSupport},
+    unsupported::{unsupported_features, UnsupportedFeature},
+    user_agent::{
+        matches_client_hints, matches_user_agent, parse_client_hints, parse_user_agent,
+        ClientHints, UserAgentOpts,
+    },
+    user_agent_regex::UserAgentRegex,
+    version::{BrowserVersion, VersionNumber},
+    version_map::{BrowserVersionMap, BrowserVersions},
+};
+
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+mod config;
+mod coverage;
+mod data;
+mod describe;
+mod error;
+mod es_edition;
+mod excluded;
+mod explain;
+mod feature_usage;
+mod intern;
+mod opts;
+mod parser;
+mod prefix;
+mod provenance;
+mod queries;
+mod semver;
+mod targets;
+#[cfg(test)]
+mod test;
+mod timeline;
+mod unsupported;
+mod user_agent;
+mod user_agent_regex;
+mod version;
+mod version_map;
+
+/// Resolve browserslist queries.
+///
+/// This is a low-level API.
+/// If you want to load queries from configuration file and
+/// resolve them automatically,
+/// use the higher-level API [`execute`] instead.
+///
+/// ```
+/// use browserslist::{Distrib, Opts, resolve};
+///
+/// let distribs = resolve(["ie <= 6"], &Opts::new()).unwrap();
+/// assert_eq!(distribs[0].name(), "ie");
+/// assert_eq!(distribs[0].version(), "6");
+/// assert_eq!(distribs[1].name(), "ie");
+/// assert_eq!(distribs[1].version(), "5.5");
+/// ```
+///
+/// When resolving queries from untrusted sources, such as user input,
+/// consider limiting the input size with [`Opts::max_query_length`] and [`Opts::max_queries`]:
+///
+/// ```
+/// use browserslist::{Error, Opts, resolve};
+///
+/// let mut opts = Opts::new();
+/// opts.max_query_length(100).max_queries(2);
+/// assert_eq!(
+///     resolve(["> 1%, last 2 versions, not dead"], &opts),
+///     Err(Error::TooManyQueries(2))
+/// );
+/// ```
+pub fn resolve<I, S>(queries: I, opts: &Opts) -> Result<Vec<Distrib>, Error>
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = S>,
+{
+    let query = join_queries(queries);
+    evaluate(parse_queries(&query, opts)?, opts)
+}
+
+/// Parse joined queries, with limits in [`Opts`] applied.
+fn parse_queries<'a>(query: &'a str, opts: &Opts) -> Result<Vec<SingleQuery<'a>>, Error> {
+    if let Some(limit) = opts.max_query_length {
+        if query.len() > limit {
+            return Err(Error::QueryTooLong(limit));
+        }
+    }
+
+    let single_queries = parse_browserslist_query(query)?.1;
+    if let Some(limit) = opts.max_queries {
+        if single_queries.len() > limit {
+            return Err(Error::TooManyQueries(limit));
+        }
+    }
+
+    Ok(single_queries)
+}
+
+/// Resolve queries which are built in this library, such as those expanded from `defaults`.
+///
+/// Unlike [`resolve`], limits in [`Opts`] aren't applied.
+pub(crate) fn resolve_builtin<I, S>(queries: I, opts: &Opts) -> Result<Vec<Distrib>, Error>
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = S>,
+{
+    evaluate(parse_browserslist_query(&join_queries(queries))?.1, opts)
+}
+
+fn join_queries<I, S>(queries: I) -> String
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = S>,
+{
+    queries
+        .into_iter()
+        .enumerate()
+        .fold(String::new(), |mut s, (i, query)| {
+            if i > 0 {
+                s.push_str(", ");
+            }
+            s.push_str(query.as_ref());
+            s
+        })
+}
+
+fn evaluate(single_queries: Vec<SingleQuery>, opts: &Opts) -> Result<Vec<Distrib>, Error> {
+    struct NoHook;
+    impl EvaluationHook for NoHook {}
+
+    evaluate_with(single_queries, opts, &mut NoHook)
+}
+
+/// Hook of [`evaluate_with`] which is called for each clause,
+/// so features like provenance and explanation can share the same evaluation.
+pub(crate) trait EvaluationHook {
+    /// Return results produced by the clause itself.
+    fn produce(&mut self, atom: QueryAtom, opts: &Opts) -> Result<Vec<Distrib>, Error> {
+        queries::query(atom, opts)
+    }
+
+    /// Called after results of the clause are combined with results of previous clauses.
+    ///
+    /// Running results are neither sorted nor deduplicated.
+    fn combined(
+        &mut self,
+        _index: usize,
+        _raw: &str,
+        _combinator: Combinator,
+        _produced: Vec<Distrib>,
+        _running: &[Distrib],
+    ) {
+    }
+}
+
+/// Combine results of clauses with `or`, `and` and `not`, then sort them.
+pub(crate) fn evaluate_with(
+    single_queries: Vec<SingleQuery>,
+    opts: &Opts,
+    hook: &mut impl EvaluationHook,
+) -> Result<Vec<Distrib>, Error> {
+    let mut distribs = vec![];
+    for (i, current) in single_queries.into_iter().enumerate() {
+        if i == 0 && current.negated {
+            return Err(Error::NotAtFirst(current.raw.to_string()));
+        }
+
+        let dist = hook.produce(current.atom, opts)?;
+        let combinator = if current.negated {
+            distribs.retain(|distrib| !dist.contains(distrib));
+            Combinator::Not
+        } else if current.is_and {
+            distribs.retain(|distrib| dist.contains(distrib));
+            Combinator::And
+        } else {
+            distribs.extend_from_slice(&dist);
+            Combinator::Or
+        };
+        hook.combined(i, current.raw, combinator, dist, &distribs);
+    }
+
+    distribs.sort_by(compare_distribs);
+    distribs.dedup();
+
+    Ok(distribs)
+}
+
+/// Compare by browser name, then by version.
+fn compare_distribs(a: &Distrib, b: &Distrib) -> Ordering {
+    a.name().cmp(b.name()).then_with(|| {
+        let version_a = a.browser_version();
+        let version_b = b.browser_version();
+        // like JavaScript-based Browserslist,
+        // numeric versions are in descending order and `TP` goes after them
+        if version_a.lower_bound().is_some() && version_b.lower_bound().is_some() {
+            version_b.cmp(&version_a)
+        } else {
+            version_a.cmp(&version_b)
+        }
+    })
+}
+
+/// Load queries from configuration with environment information,
+/// then resolve those queries.
+///
+/// If you want to resolve custom queries (not from configuration file),
+/// use the lower-level API [`resolve`] instead.
+///
+/// ```
+/// use browserslist::{Opts, execute};
+///
+/// // when no config found, it use `defaults` query
+/// assert!(!execute(&Opts::new()).unwrap().is_empty());
+/// ```
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+pub fn execute(opts: &Opts) -> Result<Vec<Distrib>, Error> {
+    resolve(config::load(opts)?, opts)
+}
@@ -0,0 +1,124 @@
+use crate::error::Error;
+use alloc::string::{String, ToString};
+use serde::{Deserialize, Serialize};
+
+/// Options for controlling the behavior of browserslist.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Opts {
+    #[serde(default)]
+    pub(crate) mobile_to_desktop: bool,
+
+    #[serde(default)]
+    pub(crate) ignore_unknown_versions: bool,
+
+    #[serde(default)]
+    pub(crate) config: Option<String>,
+
+    #[serde(default)]
+    pub(crate) env: Option<String>,
+
+    #[serde(default)]
+    pub(crate) path: Option<String>,
+
+    #[serde(default)]
+    pub(crate) throw_on_missing: bool,
+
+    #[serde(default)]
+    pub(crate) now: Option<i64>,
+
+    #[serde(default)]
+    pub(crate) max_query_length: Option<usize>,
+
+    #[serde(default)]
+    pub(crate) max_queries: Option<usize>,
+}
+
+impl Opts {
+    /// Create new options with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use desktop browsers if Can I Use doesn’t have data about this mobile version.
+    pub fn mobile_to_desktop(&mut self, flag: bool) -> &mut Self {
+        self.mobile_to_desktop = flag;
+        self
+    }
+
+    /// If `true`, ignore unknown versions then return empty result;
+    /// otherwise, reject with an error.
+    pub fn ignore_unknown_versions(&mut self, flag: bool) -> &mut Self {
+        self.ignore_unknown_versions = flag;
+        self
+    }
+
+    /// Path to configuration file with queries.
+    pub fn config<S: AsRef<str>>(&mut self, config_path: S) -> &mut Self {
+        self.config = Some(config_path.as_ref().to_string());
+        self
+    }
+
+    /// Processing environment. It will be used to take right queries from config file.
+    pub fn env<S: AsRef<str>>(&mut self, env: S) -> &mut Self {
+        self.env = Some(env.as_ref().to_string());
+        self
+    }
+
+    /// File or directory path for looking for configuration file.
+    pub fn path<S: AsRef<str>>(&mut self, path: S) -> &mut Self {
+        self.path = Some(path.as_ref().to_string());
+        self
+    }
+
+    /// Throw error on missing env.
+    pub fn throw_on_missing(&mut self, flag: bool) -> &mut Self {
+        self.throw_on_missing = flag;
+        self
+    }
+
+    /// Unix timestamp (in seconds) used as current time when evaluating queries
+    /// like `last 2 years` and `maintained node versions`.
+    ///
+    /// If it isn't set, system clock will be used,
+    /// which is only available with the `std` feature.
+    ///
+    /// Dates of Node.js release schedule are compared in UTC like JavaScript-based Browserslist,
+    /// regardless of local time zone.
+    pub fn now(&mut self, timestamp: i64) -> &mut Self {
+        self.now = Some(timestamp);
+        self
+    }
+
+    /// Reject input whose length (in bytes) exceeds the given limit.
+    ///
+    /// When multiple queries are passed, they're measured after being joined with `", "`.
+    /// Queries built in this library, such as those which `defaults` or `dead` expands to,
+    /// aren't measured, while queries loaded from configuration are.
+    /// This is useful when resolving queries from untrusted sources.
+    pub fn max_query_length(&mut self, limit: usize) -> &mut Self {
+        self.max_query_length = Some(limit);
+        self
+    }
+
+    /// Reject input which consists of more single queries than the given limit,
+    /// for example, `> 1%, not dead` consists of two single queries.
+    ///
+    /// Queries built in this library, such as those which `defaults` or `dead` expands to,
+    /// aren't counted, while queries loaded from configuration are.
+    /// This is useful when resolving queries from untrusted sources.
+    pub fn max_queries(&mut self, limit: usize) -> &mut Self {
+        self.max_queries = Some(limit);
+        self
+    }
+
+    pub(crate) fn current_timestamp(&self) -> Result<i64, Error> {
+        match self.now {
+            Some(timestamp) => Ok(timestamp),
+            #[cfg(feature = "std")]
+            None => Ok(chrono::Utc::now().timestamp()),
+            #[cfg(not(feature = "std"))]
+            None => Err(Error::MissingCurrentTime),
+        }
+    }
+}
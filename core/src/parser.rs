@@ -1,3 +1,8 @@
+// Parsers return `PResult<T>` with the lifetime of input elided, which is intended.
+// The lint doesn't exist in the minimum supported Rust version.
+#![allow(unknown_lints, mismatched_lifetime_syntaxes)]
+
+use alloc::vec::Vec;
 use nom::{
     branch::alt,
     bytes::complete::{tag, tag_no_case, take_while1, take_while_m_n},
@@ -34,7 +39,10 @@ pub enum QueryAtom<'a> {
         coverage: f32,
         stats: Stats<'a>,
     },
-    Supports(&'a str),
+    Supports {
+        name: &'a str,
+        without_prefix: bool,
+    },
     Electron(VersionRange<'a>),
     Node(VersionRange<'a>),
     Browser(&'a str, VersionRange<'a>),
@@ -208,9 +216,28 @@ fn parse_supports(input: &str) -> PResult<QueryAtom> {
     map(
         preceded(
             terminated(tag_no_case("supports"), space1),
-            take_while1(|c: char| c.is_alphanumeric() || c == '-'),
+            pair(
+                alt((
+                    recognize(pair(
+                        tag_no_case("mdn:"),
+                        take_while1(|c: char| {
+                            c.is_alphanumeric() || c == '-' || c == '_' || c == '.'
+                        }),
+                    )),
+                    take_while1(|c: char| c.is_alphanumeric() || c == '-'),
+                )),
+                opt(tuple((
+                    space1,
+                    tag_no_case("without"),
+                    space1,
+                    tag_no_case("prefix"),
+                ))),
+            ),
         ),
-        QueryAtom::Supports,
+        |(name, without_prefix)| QueryAtom::Supports {
+            name,
+            without_prefix: without_prefix.is_some(),
+        },
     )(input)
 }
 
@@ -0,0 +1,208 @@
+use crate::{
+    data::caniuse::{features::get_feature_stat, get_browser_stat, to_desktop_name},
+    error::Error,
+    queries::Distrib,
+};
+use alloc::{string::ToString, vec::Vec};
+use serde::Serialize;
+
+/// Result of [`prefix_requirements`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct PrefixRequirements {
+    prefixed: Vec<PrefixedTarget>,
+    unprefixed: Vec<Distrib>,
+    unsupported: Vec<Distrib>,
+}
+
+impl PrefixRequirements {
+    /// Return browsers which support the feature only with vendor prefix.
+    #[inline]
+    pub fn prefixed(&self) -> &[PrefixedTarget] {
+        &self.prefixed
+    }
+
+    /// Return browsers which support the feature without vendor prefix.
+    #[inline]
+    pub fn unprefixed(&self) -> &[Distrib] {
+        &self.unprefixed
+    }
+
+    /// Return browsers which don't support the feature at all.
+    ///
+    /// Can I Use doesn't have data of Node.js, so Node.js versions are always here.
+    #[inline]
+    pub fn unsupported(&self) -> &[Distrib] {
+        &self.unsupported
+    }
+
+    /// Check if any of browsers requires vendor prefix.
+    #[inline]
+    pub fn needs_prefix(&self) -> bool {
+        !self.prefixed.is_empty()
+    }
+}
+
+/// Browser which requires vendor prefix for a feature.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PrefixedTarget {
+    distrib: Distrib,
+    prefix: &'static str,
+}
+
+impl PrefixedTarget {
+    /// Return the browser.
+    #[inline]
+    pub fn distrib(&self) -> &Distrib {
+        &self.distrib
+    }
+
+    /// Return vendor prefix of the browser without dashes, such as `webkit` or `moz`.
+    #[inline]
+    pub fn prefix(&self) -> &'static str {
+        self.prefix
+    }
+}
+
+/// Find out which of the given browsers need vendor prefix for a Can I Use feature,
+/// and which prefix they need.
+///
+/// Mobile browser versions which come from desktop browsers with `mobile_to_desktop`
+/// are looked up in data of those desktop browsers.
+///
+/// ```
+/// use browserslist::{prefix_requirements, resolve, Opts};
+///
+/// let distribs = resolve(["chrome 100, ie 11"], &Opts::new()).unwrap();
+/// let requirements = prefix_requirements("es6", &distribs).unwrap();
+///
+/// assert!(!requirements.needs_prefix());
+/// assert_eq!(requirements.unprefixed()[0].to_string(), "chrome 100");
+/// assert_eq!(requirements.unsupported()[0].to_string(), "ie 11");
+/// ```
+pub fn prefix_requirements(
+    feature: &str,
+    distribs: &[Distrib],
+) -> Result<PrefixRequirements, Error> {
+    let stat = get_feature_stat(feature)
+        .ok_or_else(|| Error::UnknownBrowserFeature(feature.to_string()))?;
+
+    let mut requirements = PrefixRequirements::default();
+    for distrib in distribs {
+        let (agent, version) = data_source(distrib);
+        let prefixed = stat
+            .iter()
+            .find(|(name, v, ..)| *name == agent && *v == version)
+            .map(|(_, _, prefixed, _)| *prefixed);
+        match prefixed {
+            Some(true) => requirements.prefixed.push(PrefixedTarget {
+                distrib: *distrib,
+                prefix: agent_prefix(agent, version),
+            }),
+            Some(false) => requirements.unprefixed.push(*distrib),
+            None => requirements.unsupported.push(*distrib),
+        }
+    }
+
+    Ok(requirements)
+}
+
+/// Find Can I Use agent and version whose data describes the given browser version.
+///
+/// With `mobile_to_desktop`, a mobile browser can have versions of its desktop browser,
+/// which aren't in data of the mobile browser itself.
+fn data_source(distrib: &Distrib) -> (&'static str, &'static str) {
+    let (name, version) = (distrib.name(), distrib.version());
+    let is_own_version = get_browser_stat(name, false).is_some_and(|(_, stat)| {
+        stat.version_list
+            .iter()
+            .any(|detail| detail.version == version)
+    });
+    match to_desktop_name(name) {
+        Some(desktop_name) if !is_own_version => (desktop_name, version),
+        _ => (name, version),
+    }
+}
+
+/// Look up vendor prefix of a browser version from Can I Use agents data.
+///
+/// Prefix may vary across versions of the same browser,
+/// for example, Opera switched from `o` to `webkit`.
+fn agent_prefix(agent: &str, version: &str) -> &'static str {
+    get_browser_stat(agent, false)
+        .and_then(|(_, stat)| {
+            stat.version_list
+                .iter()
+                .find(|detail| detail.version == version)
+                .or_else(|| stat.version_list.last())
+        })
+        .map(|detail| detail.prefix)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{opts::Opts, resolve};
+    use alloc::string::String;
+
+    fn names(distribs: &[Distrib]) -> Vec<String> {
+        distribs.iter().map(|distrib| distrib.to_string()).collect()
+    }
+
+    #[test]
+    fn split_by_prefix() {
+        let distribs = resolve(["supports css-appearance"], &Opts::new()).unwrap();
+        let requirements = prefix_requirements("css-appearance", &distribs).unwrap();
+        assert!(requirements.unsupported().is_empty());
+        assert!(requirements.needs_prefix());
+        assert!(!requirements.unprefixed().is_empty());
+
+        let without_prefix =
+            resolve(["supports css-appearance without prefix"], &Opts::new()).unwrap();
+        assert_eq!(names(requirements.unprefixed()), names(&without_prefix));
+
+        let chrome = requirements
+            .prefixed()
+            .iter()
+            .find(|target| target.distrib().name() == "chrome")
+            .unwrap();
+        assert_eq!(chrome.prefix(), "webkit");
+        let firefox = requirements
+            .prefixed()
+            .iter()
+            .find(|target| target.distrib().name() == "firefox")
+            .unwrap();
+        assert_eq!(firefox.prefix(), "moz");
+    }
+
+    #[test]
+    fn unsupported() {
+        let distribs = resolve(["ie 11, node 10.0.0"], &Opts::new()).unwrap();
+        let requirements = prefix_requirements("es6", &distribs).unwrap();
+        assert!(!requirements.needs_prefix());
+        assert!(requirements.unprefixed().is_empty());
+        assert_eq!(names(requirements.unsupported()), ["ie 11", "node 10.0.0"]);
+    }
+
+    #[test]
+    fn mobile_to_desktop() {
+        let distribs = resolve(
+            ["and_chr 100, and_ff 100"],
+            Opts::new().mobile_to_desktop(true),
+        )
+        .unwrap();
+        let requirements = prefix_requirements("es6", &distribs).unwrap();
+        assert!(requirements.unsupported().is_empty());
+        assert_eq!(names(requirements.unprefixed()), names(&distribs));
+    }
+
+    #[test]
+    fn unknown_feature() {
+        assert_eq!(
+            prefix_requirements("unknown-feature", &[]),
+            Err(Error::UnknownBrowserFeature(String::from(
+                "unknown-feature"
+            )))
+        );
+    }
+}
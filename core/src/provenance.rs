@@ -0,0 +1,327 @@
+use crate::{
+    compare_distribs, error::Error, evaluate_with, join_queries, opts::Opts, parse_queries,
+    Combinator, Distrib, EvaluationHook,
+};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::ops::Range;
+use serde::Serialize;
+
+/// Result of [`resolve_with_provenance`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Provenance {
+    query: String,
+    included: Vec<IncludedDistrib>,
+    excluded: Vec<ExcludedDistrib>,
+}
+
+impl Provenance {
+    /// Return the joined query which spans of clauses refer to.
+    #[inline]
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Return resolved browsers, in the same order as [`resolve`](crate::resolve).
+    #[inline]
+    pub fn included(&self) -> &[IncludedDistrib] {
+        &self.included
+    }
+
+    /// Return browsers which were added by some clauses but removed by later clauses.
+    #[inline]
+    pub fn excluded(&self) -> &[ExcludedDistrib] {
+        &self.excluded
+    }
+}
+
+/// Resolved browser with clauses which added it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IncludedDistrib {
+    distrib: Distrib,
+    added_by: Vec<Clause>,
+}
+
+impl IncludedDistrib {
+    /// Return the browser.
+    #[inline]
+    pub fn distrib(&self) -> &Distrib {
+        &self.distrib
+    }
+
+    /// Return clauses which added this browser.
+    #[inline]
+    pub fn added_by(&self) -> &[Clause] {
+        &self.added_by
+    }
+}
+
+/// Browser which was added by some clauses, then removed by a later clause.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExcludedDistrib {
+    distrib: Distrib,
+    added_by: Vec<Clause>,
+    removed_by: Clause,
+}
+
+impl ExcludedDistrib {
+    /// Return the browser.
+    #[inline]
+    pub fn distrib(&self) -> &Distrib {
+        &self.distrib
+    }
+
+    /// Return clauses which added this browser before it was removed.
+    #[inline]
+    pub fn added_by(&self) -> &[Clause] {
+        &self.added_by
+    }
+
+    /// Return the `not` or `and` clause which removed this browser.
+    #[inline]
+    pub fn removed_by(&self) -> &Clause {
+        &self.removed_by
+    }
+}
+
+/// A single query in the joined query, such as `not dead` in `> 1%, not dead`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Clause {
+    index: usize,
+    raw: String,
+    span: Range<usize>,
+}
+
+impl Clause {
+    /// Return position of this clause among all clauses, starting from `0`.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Return text of this clause, including `not` but excluding `,`, `or` and `and`.
+    #[inline]
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    /// Return byte range of this clause in [the joined query](Provenance::query).
+    #[inline]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+/// Resolve browserslist queries like [`resolve`](crate::resolve),
+/// and record which clauses added each browser and which clauses removed others.
+///
+/// ```
+/// use browserslist::{resolve_with_provenance, Opts};
+///
+/// let provenance = resolve_with_provenance(["ie >= 10, ie 11, not ie 10"], &Opts::new()).unwrap();
+///
+/// let ie11 = &provenance.included()[0];
+/// assert_eq!(ie11.distrib().to_string(), "ie 11");
+/// assert_eq!(
+///     ie11.added_by().iter().map(|clause| clause.raw()).collect::<Vec<_>>(),
+///     ["ie >= 10", "ie 11"]
+/// );
+///
+/// let ie10 = &provenance.excluded()[0];
+/// assert_eq!(ie10.distrib().to_string(), "ie 10");
+/// assert_eq!(ie10.removed_by().raw(), "not ie 10");
+/// assert_eq!(&provenance.query()[ie10.removed_by().span()], "not ie 10");
+/// ```
+pub fn resolve_with_provenance<I, S>(queries: I, opts: &Opts) -> Result<Provenance, Error>
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = S>,
+{
+    let query = join_queries(queries);
+    let single_queries = parse_queries(&query, opts)?;
+
+    let mut recorder = Recorder {
+        query: &query,
+        clauses: Vec::with_capacity(single_queries.len()),
+        records: BTreeMap::new(),
+    };
+    evaluate_with(single_queries, opts, &mut recorder)?;
+    let Recorder {
+        clauses, records, ..
+    } = recorder;
+
+    let to_clauses = |indexes: Vec<usize>| {
+        indexes
+            .into_iter()
+            .map(|i| clauses[i].clone())
+            .collect::<Vec<_>>()
+    };
+    let mut included = Vec::new();
+    let mut excluded = Vec::new();
+    for (distrib, added_by, removed_by) in records.into_values() {
+        match removed_by {
+            Some(removed_by) => excluded.push(ExcludedDistrib {
+                distrib,
+                added_by: to_clauses(added_by),
+                removed_by: clauses[removed_by].clone(),
+            }),
+            None => included.push(IncludedDistrib {
+                distrib,
+                added_by: to_clauses(added_by),
+            }),
+        }
+    }
+    included.sort_by(|a, b| compare_distribs(&a.distrib, &b.distrib));
+    excluded.sort_by(|a, b| compare_distribs(&a.distrib, &b.distrib));
+
+    Ok(Provenance {
+        query,
+        included,
+        excluded,
+    })
+}
+
+/// Browser with indexes of clauses which added it and index of the clause which removed it.
+type Record = (Distrib, Vec<usize>, Option<usize>);
+
+struct Recorder<'a> {
+    query: &'a str,
+    clauses: Vec<Clause>,
+    /// Records keyed by name and version.
+    records: BTreeMap<(&'static str, &'static str), Record>,
+}
+
+impl EvaluationHook for Recorder<'_> {
+    fn combined(
+        &mut self,
+        index: usize,
+        raw: &str,
+        combinator: Combinator,
+        produced: Vec<Distrib>,
+        _running: &[Distrib],
+    ) {
+        let start = raw.as_ptr() as usize - self.query.as_ptr() as usize;
+        self.clauses.push(Clause {
+            index,
+            raw: raw.to_string(),
+            span: start..start + raw.len(),
+        });
+
+        if combinator == Combinator::Or {
+            for distrib in produced {
+                let (_, added_by, removed_by) = self
+                    .records
+                    .entry((distrib.name(), distrib.version()))
+                    .or_insert_with(|| (distrib, Vec::new(), None));
+                if removed_by.take().is_some() {
+                    added_by.clear();
+                }
+                if added_by.last() != Some(&index) {
+                    added_by.push(index);
+                }
+            }
+        } else {
+            let negated = combinator == Combinator::Not;
+            let matched = produced
+                .iter()
+                .map(|distrib| (distrib.name(), distrib.version()))
+                .collect::<BTreeSet<_>>();
+            self.records
+                .iter_mut()
+                .filter(|(key, (_, _, removed_by))| {
+                    removed_by.is_none() && matched.contains(key) == negated
+                })
+                .for_each(|(_, (_, _, removed_by))| *removed_by = Some(index));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resolve;
+    use test_case::test_case;
+
+    #[test_case("defaults"; "defaults")]
+    #[test_case("> 1%, last 2 versions, not dead"; "or and not")]
+    #[test_case("last 2 versions and > 0.5%, ie 11"; "and")]
+    #[test_case("chrome > 100, not chrome 110, chrome >= 110"; "added again")]
+    #[test_case("node >= 16, electron 20"; "node and electron")]
+    fn same_as_resolve(query: &str) {
+        let opts = Opts::new();
+        let provenance = resolve_with_provenance([query], &opts).unwrap();
+        assert_eq!(
+            provenance
+                .included()
+                .iter()
+                .map(|included| *included.distrib())
+                .collect::<Vec<_>>(),
+            resolve([query], &opts).unwrap()
+        );
+        assert!(provenance
+            .included()
+            .iter()
+            .all(|included| !included.added_by().is_empty()));
+        assert!(provenance.excluded().iter().all(|excluded| excluded
+            .added_by()
+            .iter()
+            .all(|clause| clause.index() < excluded.removed_by().index())));
+    }
+
+    #[test]
+    fn removed_by_and() {
+        let provenance =
+            resolve_with_provenance(["ie 10, ie 11, chrome 100 and ie >= 11"], &Opts::new())
+                .unwrap();
+        let excluded = provenance
+            .excluded()
+            .iter()
+            .map(|excluded| {
+                (
+                    excluded.distrib().to_string(),
+                    excluded.removed_by().raw().to_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            excluded,
+            [
+                ("chrome 100".to_string(), "ie >= 11".to_string()),
+                ("ie 10".to_string(), "ie >= 11".to_string())
+            ]
+        );
+        assert_eq!(
+            &provenance.query()[provenance.excluded()[0].removed_by().span()],
+            "ie >= 11"
+        );
+    }
+
+    #[test]
+    fn added_again() {
+        let provenance =
+            resolve_with_provenance(["chrome 100, not chrome 100", "chrome 100"], &Opts::new())
+                .unwrap();
+        assert!(provenance.excluded().is_empty());
+        let added_by = provenance.included()[0].added_by();
+        assert_eq!(added_by.len(), 1);
+        assert_eq!(added_by[0].index(), 2);
+        assert_eq!(added_by[0].span(), 28..38);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            resolve_with_provenance(["not ie 11"], &Opts::new()),
+            Err(Error::NotAtFirst("not ie 11".to_string()))
+        );
+        assert_eq!(
+            resolve_with_provenance(["> 1%, ie 11"], Opts::new().max_queries(1)),
+            Err(Error::TooManyQueries(1))
+        );
+    }
+}
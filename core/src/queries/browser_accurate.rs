@@ -4,7 +4,11 @@ use crate::{
     error::Error,
     opts::Opts,
 };
-use std::borrow::Cow;
+use alloc::{
+    borrow::{Cow, ToOwned},
+    string::ToString,
+    vec,
+};
 
 pub(super) fn browser_accurate(name: &str, version: &str, opts: &Opts) -> QueryResult {
     let original_name = name;
@@ -18,15 +22,8 @@ pub(super) fn browser_accurate(name: &str, version: &str, opts: &Opts) -> QueryR
     let (name, stat) = get_browser_stat(name, opts.mobile_to_desktop)
         .ok_or_else(|| Error::BrowserNotFound(name.to_string()))?;
 
-    if let Some(version) = normalize_version(
-        stat,
-        if original_version.eq_ignore_ascii_case("tp") {
-            "TP"
-        } else {
-            version
-        },
-    ) {
-        Ok(vec![Distrib::new(name, version.to_owned())])
+    if let Some(version) = normalize_version(stat, version) {
+        Ok(vec![Distrib::new(name, version)])
     } else {
         let version = if version.contains('.') {
             Cow::Borrowed(version.trim_end_matches(".0"))
@@ -36,7 +33,7 @@ pub(super) fn browser_accurate(name: &str, version: &str, opts: &Opts) -> QueryR
             Cow::Owned(v)
         };
         if let Some(version) = normalize_version(stat, &version) {
-            Ok(vec![Distrib::new(name, version.to_owned())])
+            Ok(vec![Distrib::new(name, version)])
         } else if opts.ignore_unknown_versions {
             Ok(vec![])
         } else {
@@ -5,6 +5,7 @@ use crate::{
     opts::Opts,
     semver::Version,
 };
+use alloc::string::ToString;
 
 pub(super) fn browser_bounded_range(name: &str, from: &str, to: &str, opts: &Opts) -> QueryResult {
     let (name, stat) = get_browser_stat(name, opts.mobile_to_desktop)
@@ -22,7 +23,7 @@ pub(super) fn browser_bounded_range(name: &str, from: &str, to: &str, opts: &Opt
         .version_list
         .iter()
         .filter(|version| version.release_date.is_some())
-        .map(|version| &*version.version)
+        .map(|version| version.version)
         .filter(|version| {
             let version = version.parse().unwrap_or_default();
             from <= version && version <= to
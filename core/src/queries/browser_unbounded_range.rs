@@ -6,6 +6,7 @@ use crate::{
     parser::Comparator,
     semver::Version,
 };
+use alloc::string::ToString;
 
 pub(super) fn browser_unbounded_range(
     name: &str,
@@ -16,7 +17,7 @@ pub(super) fn browser_unbounded_range(
     let (name, stat) = get_browser_stat(name, opts.mobile_to_desktop)
         .ok_or_else(|| Error::BrowserNotFound(name.to_string()))?;
     let version: Version = BROWSER_VERSION_ALIASES
-        .get(&name.into())
+        .get(name)
         .and_then(|alias| alias.get(version).copied())
         .unwrap_or(version)
         .parse()
@@ -26,7 +27,7 @@ pub(super) fn browser_unbounded_range(
         .version_list
         .iter()
         .filter(|version| version.release_date.is_some())
-        .map(|version| &*version.version)
+        .map(|version| version.version)
         .filter(|v| {
             let v: Version = v.parse().unwrap_or_default();
             match comparator {
@@ -0,0 +1,42 @@
+use super::QueryResult;
+use crate::{error::Error, opts::Opts};
+use alloc::{string::String, vec::Vec};
+
+pub(super) fn browserslist_config(opts: &Opts) -> QueryResult {
+    #[cfg(any(target_arch = "wasm32", not(feature = "std")))]
+    {
+        crate::resolve_builtin(["defaults"], opts)
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "std"))]
+    {
+        crate::execute(opts)
+    }
+}
+
+/// Load queries which `browserslist config` query stands for.
+pub(super) fn browserslist_config_queries(opts: &Opts) -> Result<Vec<String>, Error> {
+    #[cfg(any(target_arch = "wasm32", not(feature = "std")))]
+    {
+        let _ = opts;
+        Ok(alloc::vec![String::from("defaults")])
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "std"))]
+    {
+        crate::config::load(opts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::run_compare;
+    use test_case::test_case;
+
+    #[test_case("browserslist config"; "basic")]
+    #[test_case("Browserslist Config"; "case insensitive")]
+    fn valid(query: &str) {
+        run_compare(query, &Opts::new());
+    }
+}
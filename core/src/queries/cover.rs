@@ -1,6 +1,7 @@
 use super::{Distrib, QueryResult};
 use crate::data::caniuse::CANIUSE_GLOBAL_USAGE;
-use std::ops::ControlFlow;
+use alloc::vec;
+use core::ops::ControlFlow;
 
 pub(super) fn cover(coverage: f32) -> QueryResult {
     let result = CANIUSE_GLOBAL_USAGE.iter().try_fold(
@@ -15,14 +16,13 @@ pub(super) fn cover(coverage: f32) -> QueryResult {
         },
     );
     match result {
-        ControlFlow::Break((distribs, _)) => Ok(distribs),
-        _ => unreachable!(),
+        ControlFlow::Break((distribs, _)) | ControlFlow::Continue((distribs, _)) => Ok(distribs),
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{opts::Opts, test::run_compare};
+    use crate::{opts::Opts, resolve, test::run_compare};
     use test_case::test_case;
 
     #[test_case("cover 0.1%"; "global")]
@@ -30,4 +30,9 @@ mod tests {
     fn valid(query: &str) {
         run_compare(query, &Opts::new());
     }
+
+    #[test]
+    fn exceed_total_usage() {
+        assert!(resolve(["cover 1000%"], &Opts::new()).is_ok());
+    }
 }
@@ -1,29 +1,25 @@
 use super::{Distrib, QueryResult};
 use crate::{data::caniuse::region::get_usage_by_region, error::Error};
-use std::ops::ControlFlow;
+use alloc::{string::ToString, vec};
+use core::ops::ControlFlow;
 
 pub(super) fn cover_by_region(coverage: f32, region: &str) -> QueryResult {
-    let normalized_region = if region.len() == 2 {
-        region.to_uppercase()
-    } else {
-        region.to_lowercase()
-    };
-
-    if let Some(region_data) = get_usage_by_region(&normalized_region) {
+    if let Some(region_data) = get_usage_by_region(region) {
         let result = region_data.iter().try_fold(
             (vec![], 0.0),
             |(mut distribs, total), (name, version, usage)| {
                 if total >= coverage || *usage == 0.0 {
                     ControlFlow::Break((distribs, total))
                 } else {
-                    distribs.push(Distrib::new(name, *version));
+                    distribs.push(Distrib::new(name, version));
                     ControlFlow::Continue((distribs, total + usage))
                 }
             },
         );
         match result {
-            ControlFlow::Break((distribs, _)) => Ok(distribs),
-            _ => unreachable!(),
+            ControlFlow::Break((distribs, _)) | ControlFlow::Continue((distribs, _)) => {
+                Ok(distribs)
+            }
         }
     } else {
         Err(Error::UnknownRegion(region.to_string()))
@@ -32,7 +28,7 @@ pub(super) fn cover_by_region(coverage: f32, region: &str) -> QueryResult {
 
 #[cfg(test)]
 mod tests {
-    use crate::{opts::Opts, test::run_compare};
+    use crate::{opts::Opts, resolve, test::run_compare};
     use test_case::test_case;
 
     #[test_case("cover 0.1% in US"; "country")]
@@ -42,4 +38,9 @@ mod tests {
     fn valid(query: &str) {
         run_compare(query, &Opts::new());
     }
+
+    #[test]
+    fn exceed_total_usage() {
+        assert!(resolve(["cover 1000% in US"], &Opts::new()).is_ok());
+    }
 }
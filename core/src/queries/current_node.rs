@@ -0,0 +1,75 @@
+use super::QueryResult;
+use crate::error::Error;
+
+pub(super) fn current_node() -> QueryResult {
+    #[cfg(all(target_arch = "wasm32", feature = "std"))]
+    {
+        use super::Distrib;
+        use crate::{intern::intern, version::BrowserVersion};
+        use alloc::vec;
+        use js_sys::{global, Reflect};
+
+        let obj_process = Reflect::get(&global(), &"process".into())
+            .map_err(|_| Error::UnsupportedCurrentNode)?;
+        let obj_versions = Reflect::get(&obj_process, &"versions".into())
+            .map_err(|_| Error::UnsupportedCurrentNode)?;
+        let version = Reflect::get(&obj_versions, &"node".into())
+            .map_err(|_| Error::UnsupportedCurrentNode)?
+            .as_string()
+            .ok_or(Error::UnsupportedCurrentNode)?;
+        version
+            .parse::<BrowserVersion>()
+            .map_err(|_| Error::UnsupportedCurrentNode)?;
+        Ok(vec![Distrib::new("node", intern(&version))])
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "std"))]
+    {
+        use super::Distrib;
+        use crate::{intern::intern, version::BrowserVersion};
+        use alloc::vec;
+        use std::{process::Command, string::String};
+
+        let output = Command::new("node")
+            .arg("-v")
+            .output()
+            .map_err(|_| Error::UnsupportedCurrentNode)?;
+        let version = String::from_utf8_lossy(&output.stdout);
+        let version = version.trim().trim_start_matches('v');
+        version
+            .parse::<BrowserVersion>()
+            .map_err(|_| Error::UnsupportedCurrentNode)?;
+
+        Ok(vec![Distrib::new("node", intern(version))])
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        Err(Error::UnsupportedCurrentNode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::Error, opts::Opts, resolve};
+    use std::process::Command;
+    use test_case::test_case;
+
+    #[test_case("current node"; "basic")]
+    #[test_case("Current Node"; "case insensitive")]
+    #[test_case("current      node"; "more spaces")]
+    fn valid(query: &str) {
+        // result depends on the environment, so it can't be recorded as fixture
+        let result = resolve([query], &Opts::new());
+        match Command::new("node").arg("-v").output() {
+            Ok(output) => {
+                let version = String::from_utf8(output.stdout).unwrap();
+                assert_eq!(
+                    result.unwrap()[0].to_string(),
+                    format!("node {}", version.trim().trim_start_matches('v'))
+                );
+            }
+            Err(_) => assert_eq!(result, Err(Error::UnsupportedCurrentNode)),
+        }
+    }
+}
@@ -1,11 +1,10 @@
 use super::QueryResult;
-use crate::{opts::Opts, resolve};
+use crate::{opts::Opts, resolve_builtin};
+
+pub(super) const DEFAULTS: &[&str] = &["> 0.5%", "last 2 versions", "Firefox ESR", "not dead"];
 
 pub(super) fn defaults(opts: &Opts) -> QueryResult {
-    resolve(
-        ["> 0.5%", "last 2 versions", "Firefox ESR", "not dead"],
-        opts,
-    )
+    resolve_builtin(DEFAULTS, opts)
 }
 
 #[cfg(test)]
@@ -3,6 +3,7 @@ use crate::{
     data::electron::{parse_version, ELECTRON_VERSIONS},
     error::Error,
 };
+use alloc::{string::ToString, vec};
 
 pub(super) fn electron_accurate(version: &str) -> QueryResult {
     let version_str = version;
@@ -3,6 +3,7 @@ use crate::{
     data::electron::{parse_version, ELECTRON_VERSIONS},
     error::Error,
 };
+use alloc::string::ToString;
 
 pub(super) fn electron_bounded_range(from: &str, to: &str) -> QueryResult {
     let from_str = from;
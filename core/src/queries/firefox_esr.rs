@@ -1,9 +1,8 @@
 use super::{Distrib, QueryResult};
+use alloc::vec;
 
 pub(super) fn firefox_esr() -> QueryResult {
-    Ok(vec![
-        Distrib::new("firefox", "102"),
-    ])
+    Ok(vec![Distrib::new("firefox", "102")])
 }
 
 #[cfg(test)]
@@ -20,7 +20,7 @@ pub(super) fn last_n_browsers(count: usize, opts: &Opts) -> QueryResult {
                 .filter(|version| version.release_date.is_some())
                 .rev()
                 .take(count)
-                .map(move |version| Distrib::new(name, &*version.version))
+                .map(move |version| Distrib::new(name, version.version))
         })
         .collect();
 
@@ -30,7 +30,7 @@ pub(super) fn last_n_browsers(count: usize, opts: &Opts) -> QueryResult {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test::run_compare;
+    use crate::{resolve, test::run_compare};
     use test_case::test_case;
 
     #[test_case("last 2 versions"; "basic")]
@@ -40,4 +40,11 @@ mod tests {
     fn valid(query: &str) {
         run_compare(query, &Opts::new());
     }
+
+    #[test_case("last 0 versions"; "versions")]
+    #[test_case("last 0 major versions"; "major versions")]
+    #[test_case("last 0 android versions"; "android versions")]
+    fn zero(query: &str) {
+        assert!(resolve([query], &Opts::new()).unwrap().is_empty());
+    }
 }
@@ -1,8 +1,13 @@
 use super::{Distrib, QueryResult};
 use crate::data::electron::ELECTRON_VERSIONS;
+use alloc::vec;
 use itertools::Itertools;
 
 pub(super) fn last_n_electron_major(count: usize) -> QueryResult {
+    if count == 0 {
+        return Ok(vec![]);
+    }
+
     let minimum = ELECTRON_VERSIONS
         .iter()
         .rev()
@@ -23,7 +28,7 @@ pub(super) fn last_n_electron_major(count: usize) -> QueryResult {
 
 #[cfg(test)]
 mod tests {
-    use crate::{opts::Opts, test::run_compare};
+    use crate::{opts::Opts, resolve, test::run_compare};
     use test_case::test_case;
 
     #[test_case("last 2 electron major versions"; "basic")]
@@ -32,4 +37,11 @@ mod tests {
     fn valid(query: &str) {
         run_compare(query, &Opts::new());
     }
+
+    #[test]
+    fn zero() {
+        assert!(resolve(["last 0 electron major versions"], &Opts::new())
+            .unwrap()
+            .is_empty());
+    }
 }
@@ -3,9 +3,14 @@ use crate::{
     data::caniuse::{get_browser_stat, CANIUSE_BROWSERS},
     opts::Opts,
 };
+use alloc::vec;
 use itertools::Itertools;
 
 pub(super) fn last_n_major_browsers(count: usize, opts: &Opts) -> QueryResult {
+    if count == 0 {
+        return Ok(vec![]);
+    }
+
     let distribs = CANIUSE_BROWSERS
         .keys()
         .filter_map(|name| get_browser_stat(name, opts.mobile_to_desktop))
@@ -30,7 +35,7 @@ pub(super) fn last_n_major_browsers(count: usize, opts: &Opts) -> QueryResult {
             stat.version_list
                 .iter()
                 .filter(|version| version.release_date.is_some())
-                .map(|version| &*version.version)
+                .map(|version| version.version)
                 .filter(move |version| {
                     version.split('.').next().unwrap().parse().unwrap_or(0) >= minimum
                 })
@@ -45,7 +50,7 @@ pub(super) fn last_n_major_browsers(count: usize, opts: &Opts) -> QueryResult {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test::run_compare;
+    use crate::{resolve, test::run_compare};
     use test_case::test_case;
 
     #[test_case("last 2 major versions"; "basic")]
@@ -54,4 +59,11 @@ mod tests {
     fn valid(query: &str) {
         run_compare(query, &Opts::new());
     }
+
+    #[test]
+    fn zero() {
+        assert!(resolve(["last 0 major versions"], &Opts::new())
+            .unwrap()
+            .is_empty());
+    }
 }
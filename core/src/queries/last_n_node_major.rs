@@ -1,8 +1,13 @@
 use super::{Distrib, QueryResult};
 use crate::{data::node::NODE_VERSIONS, semver::Version};
+use alloc::vec;
 use itertools::Itertools;
 
 pub(super) fn last_n_node_major(count: usize) -> QueryResult {
+    if count == 0 {
+        return Ok(vec![]);
+    }
+
     let minimum = NODE_VERSIONS
         .iter()
         .rev()
@@ -33,7 +38,7 @@ pub(super) fn last_n_node_major(count: usize) -> QueryResult {
 
 #[cfg(test)]
 mod tests {
-    use crate::{opts::Opts, test::run_compare};
+    use crate::{opts::Opts, resolve, test::run_compare};
     use test_case::test_case;
 
     #[test_case("last 2 node major versions"; "basic")]
@@ -42,4 +47,11 @@ mod tests {
     fn valid(query: &str) {
         run_compare(query, &Opts::new());
     }
+
+    #[test]
+    fn zero() {
+        assert!(resolve(["last 0 node major versions"], &Opts::new())
+            .unwrap()
+            .is_empty());
+    }
 }
@@ -1,5 +1,6 @@
 use super::{count_android_filter, should_filter_android, Distrib, QueryResult};
 use crate::{data::caniuse::get_browser_stat, error::Error, opts::Opts};
+use alloc::string::ToString;
 
 pub(super) fn last_n_x_browsers(count: usize, name: &str, opts: &Opts) -> QueryResult {
     let (name, stat) = get_browser_stat(name, opts.mobile_to_desktop)
@@ -16,7 +17,7 @@ pub(super) fn last_n_x_browsers(count: usize, name: &str, opts: &Opts) -> QueryR
         .filter(|version| version.release_date.is_some())
         .rev()
         .take(count)
-        .map(|version| Distrib::new(name, &*version.version))
+        .map(|version| Distrib::new(name, version.version))
         .collect();
     Ok(distribs)
 }
@@ -1,10 +1,14 @@
 use super::{count_android_filter, should_filter_android, Distrib, QueryResult};
 use crate::{data::caniuse::get_browser_stat, error::Error, opts::Opts};
+use alloc::{string::ToString, vec};
 use itertools::Itertools;
 
 pub(super) fn last_n_x_major_browsers(count: usize, name: &str, opts: &Opts) -> QueryResult {
     let (name, stat) = get_browser_stat(name, opts.mobile_to_desktop)
         .ok_or_else(|| Error::BrowserNotFound(name.to_string()))?;
+    if count == 0 {
+        return Ok(vec![]);
+    }
     let count = if should_filter_android(name, opts.mobile_to_desktop) {
         count_android_filter(count, opts.mobile_to_desktop)
     } else {
@@ -14,7 +18,7 @@ pub(super) fn last_n_x_major_browsers(count: usize, name: &str, opts: &Opts) ->
         .version_list
         .iter()
         .filter(|version| version.release_date.is_some())
-        .map(|version| &*version.version)
+        .map(|version| version.version)
         .rev()
         .map(|version| version.split('.').next().unwrap())
         .dedup()
@@ -26,7 +30,7 @@ pub(super) fn last_n_x_major_browsers(count: usize, name: &str, opts: &Opts) ->
         .version_list
         .iter()
         .filter(|version| version.release_date.is_some())
-        .map(|version| &*version.version)
+        .map(|version| version.version)
         .filter(move |version| version.split('.').next().unwrap().parse().unwrap_or(0) >= minimum)
         .rev()
         .map(move |version| Distrib::new(name, version))
@@ -38,7 +42,7 @@ pub(super) fn last_n_x_major_browsers(count: usize, name: &str, opts: &Opts) ->
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test::run_compare;
+    use crate::{resolve, test::run_compare};
     use test_case::test_case;
 
     #[test_case("last 2 edge major versions"; "basic")]
@@ -58,4 +62,10 @@ mod tests {
             Opts::new().mobile_to_desktop(true),
         );
     }
+
+    #[test_case("last 0 chrome major versions"; "basic")]
+    #[test_case("last 0 android major versions"; "android")]
+    fn zero(query: &str) {
+        assert!(resolve([query], &Opts::new()).unwrap().is_empty());
+    }
 }
@@ -0,0 +1,52 @@
+use super::{Distrib, QueryResult};
+use crate::{
+    data::node::{NODE_VERSIONS, RELEASE_SCHEDULE},
+    error::Error,
+    opts::Opts,
+};
+use chrono::DateTime;
+
+pub(super) fn maintained_node(opts: &Opts) -> QueryResult {
+    let timestamp = opts.current_timestamp()?;
+    let now = DateTime::from_timestamp(timestamp, 0)
+        .ok_or(Error::CurrentTimeOutOfRange(timestamp))?
+        .naive_utc();
+
+    let versions = RELEASE_SCHEDULE
+        .iter()
+        .filter(|(_, (start, end))| *start < now && now < *end)
+        .filter_map(|(version, _)| {
+            NODE_VERSIONS
+                .iter()
+                .rev()
+                .find(|v| v.split('.').next().unwrap() == *version)
+        })
+        .map(|version| Distrib::new("node", version))
+        .collect();
+    Ok(versions)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        opts::Opts,
+        test::{run_compare, should_failed},
+    };
+    use test_case::test_case;
+
+    #[test_case("maintained node versions"; "basic")]
+    #[test_case("Maintained Node Versions"; "case insensitive")]
+    #[test_case("maintained   node     versions"; "more spaces")]
+    fn valid(query: &str) {
+        run_compare(query, &Opts::new());
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            should_failed("maintained node versions", Opts::new().now(i64::MAX)),
+            Error::CurrentTimeOutOfRange(i64::MAX)
+        );
+    }
+}
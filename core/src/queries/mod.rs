@@ -0,0 +1,524 @@
+use crate::{
+    data::{
+        caniuse::{self, region::get_usage_by_region},
+        electron::ELECTRON_VERSIONS,
+        node::NODE_VERSIONS,
+    },
+    error::Error,
+    intern::{self, find_bundled},
+    opts::Opts,
+    parser::{QueryAtom, Stats, VersionRange},
+    version::BrowserVersion,
+};
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Display;
+use serde::{Deserialize, Deserializer, Serialize};
+
+mod browser_accurate;
+mod browser_bounded_range;
+mod browser_unbounded_range;
+mod browserslist_config;
+mod cover;
+mod cover_by_region;
+mod current_node;
+mod dead;
+mod defaults;
+mod electron_accurate;
+mod electron_bounded_range;
+mod electron_unbounded_range;
+mod firefox_esr;
+mod last_n_browsers;
+mod last_n_electron;
+mod last_n_electron_major;
+mod last_n_major_browsers;
+mod last_n_node;
+mod last_n_node_major;
+mod last_n_x_browsers;
+mod last_n_x_major_browsers;
+mod maintained_node;
+mod node_accurate;
+mod node_bounded_range;
+mod node_unbounded_range;
+mod op_mini;
+mod percentage;
+mod percentage_by_region;
+mod phantom;
+mod since;
+mod supports;
+mod unreleased_browsers;
+mod unreleased_electron;
+mod unreleased_x_browsers;
+mod years;
+
+/// Representation of browser name (or `node`) and its version.
+///
+/// When converting it to string, it will be formatted as the output of
+/// [browserslist](https://github.com/browserslist/browserslist). For example:
+///
+/// ```
+/// use browserslist::{Opts, resolve};
+///
+/// let distrib = &resolve(["firefox 93"], &Opts::new()).unwrap()[0];
+///
+/// assert_eq!(distrib.name(), "firefox");
+/// assert_eq!(distrib.version(), "93");
+/// ```
+///
+/// Both browser name and version are borrowed from the bundled data,
+/// so it's cheap to copy and compare.
+/// Versions which don't come from bundled data (such as `current node`) are interned.
+///
+/// When deserializing, only browsers and versions in the bundled data are accepted,
+/// so versions of `current node` which aren't bundled can't be deserialized.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+pub struct Distrib(&'static str, &'static str);
+
+impl Distrib {
+    #[inline]
+    pub(crate) fn new(name: &'static str, version: &'static str) -> Self {
+        Self(name, version)
+    }
+
+    /// Create from static strings.
+    ///
+    /// This is only used by code generated by `browserslist-macros`,
+    /// so it isn't a part of public API.
+    /// It panics, or fails to compile when evaluated in const context,
+    /// if the browser and version aren't from bundled data:
+    ///
+    /// ```compile_fail
+    /// use browserslist::Distrib;
+    ///
+    /// const TARGETS: &[Distrib] = &[Distrib::__from_static("yuru", "1.0")];
+    /// ```
+    #[doc(hidden)]
+    pub const fn __from_static(name: &'static str, version: &'static str) -> Self {
+        assert!(
+            intern::is_bundled(name, version),
+            "browser or version isn't from bundled data"
+        );
+        Self(name, version)
+    }
+
+    /// Return browser name, or `node`.
+    ///
+    /// ```
+    /// use browserslist::{Opts, resolve};
+    ///
+    /// let distrib = &resolve(["firefox 93"], &Opts::new()).unwrap()[0];
+    ///
+    /// assert_eq!(distrib.name(), "firefox");
+    /// ```
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.0
+    }
+
+    /// Return version string.
+    ///
+    /// ```
+    /// use browserslist::{Opts, resolve};
+    ///
+    /// let distrib = &resolve(["firefox 93"], &Opts::new()).unwrap()[0];
+    ///
+    /// assert_eq!(distrib.version(), "93");
+    /// ```
+    #[inline]
+    pub fn version(&self) -> &'static str {
+        self.1
+    }
+
+    /// Return version in typed form, which can be compared and inspected.
+    ///
+    /// Versions are validated when resolving queries or deserializing, so they can always be parsed.
+    /// It only returns [`BrowserVersion::All`] as a fallback for an unparsable version.
+    ///
+    /// ```
+    /// use browserslist::{BrowserVersion, Opts, resolve};
+    ///
+    /// let distrib = &resolve(["safari tp"], &Opts::new()).unwrap()[0];
+    ///
+    /// assert_eq!(distrib.browser_version(), BrowserVersion::TechnologyPreview);
+    /// ```
+    pub fn browser_version(&self) -> BrowserVersion {
+        self.1.parse().unwrap_or(BrowserVersion::All)
+    }
+
+    /// Return release date as Unix timestamp (in seconds) from Can I Use data.
+    ///
+    /// It returns `None` for unreleased versions and Node.js versions.
+    ///
+    /// ```
+    /// use browserslist::{Opts, resolve};
+    ///
+    /// let distrib = &resolve(["firefox 93"], &Opts::new()).unwrap()[0];
+    ///
+    /// assert!(distrib.release_date().is_some());
+    /// ```
+    pub fn release_date(&self) -> Option<i64> {
+        self.version_detail().and_then(|detail| detail.release_date)
+    }
+
+    /// Return global usage (in percentage) from Can I Use data.
+    ///
+    /// It returns `0.0` if Can I Use doesn't have usage data of this version,
+    /// for example, Node.js versions.
+    /// Like [`release_date`](Self::release_date), mobile versions which come from
+    /// desktop browsers with `mobile_to_desktop` use data of those desktop versions.
+    ///
+    /// ```
+    /// use browserslist::{Opts, resolve};
+    ///
+    /// let distrib = &resolve(["node 16.0.0"], &Opts::new()).unwrap()[0];
+    ///
+    /// assert_eq!(distrib.global_usage(), 0.0);
+    /// ```
+    pub fn global_usage(&self) -> f32 {
+        self.version_detail()
+            .map(|detail| detail.global_usage)
+            .unwrap_or_default()
+    }
+
+    /// Return usage (in percentage) in the given region,
+    /// which can be a country code like `US` or a continent like `alt-eu`.
+    ///
+    /// ```
+    /// use browserslist::{Error, Opts, resolve};
+    ///
+    /// let distrib = &resolve(["firefox 93"], &Opts::new()).unwrap()[0];
+    ///
+    /// assert!(distrib.usage_in_region("US").is_ok());
+    /// assert_eq!(
+    ///     distrib.usage_in_region("XX"),
+    ///     Err(Error::UnknownRegion("XX".into()))
+    /// );
+    /// ```
+    pub fn usage_in_region(&self, region: &str) -> Result<f32, Error> {
+        let region_data =
+            get_usage_by_region(region).ok_or_else(|| Error::UnknownRegion(region.to_string()))?;
+        Ok(region_data
+            .iter()
+            .find(|(name, version, _)| *name == self.0 && *version == self.1)
+            .map(|(_, _, usage)| *usage)
+            .unwrap_or_default())
+    }
+
+    /// Check if this version has been released.
+    ///
+    /// All bundled Node.js versions are considered released.
+    ///
+    /// ```
+    /// use browserslist::{Opts, resolve};
+    ///
+    /// let distrib = &resolve(["safari tp"], &Opts::new()).unwrap()[0];
+    ///
+    /// assert!(!distrib.is_released());
+    /// ```
+    pub fn is_released(&self) -> bool {
+        if self.is_node() {
+            NODE_VERSIONS.contains(&self.1)
+        } else {
+            self.release_date().is_some()
+        }
+    }
+
+    /// Check if this is Safari Technology Preview.
+    ///
+    /// ```
+    /// use browserslist::{Opts, resolve};
+    ///
+    /// let distrib = &resolve(["safari tp"], &Opts::new()).unwrap()[0];
+    ///
+    /// assert!(distrib.is_technology_preview());
+    /// ```
+    #[inline]
+    pub fn is_technology_preview(&self) -> bool {
+        self.1 == "TP"
+    }
+
+    /// Check if this is a Node.js version.
+    ///
+    /// ```
+    /// use browserslist::{Opts, resolve};
+    ///
+    /// let distrib = &resolve(["node 16.0.0"], &Opts::new()).unwrap()[0];
+    ///
+    /// assert!(distrib.is_node());
+    /// ```
+    #[inline]
+    pub fn is_node(&self) -> bool {
+        self.0 == "node"
+    }
+
+    /// Return Electron versions which are based on this Chromium version,
+    /// so it's empty for browsers other than Chrome.
+    ///
+    /// Queries like `electron 20` resolve to Chrome versions,
+    /// and this can be used to map them back.
+    ///
+    /// ```
+    /// use browserslist::{Opts, resolve};
+    ///
+    /// let distrib = &resolve(["electron 20.0"], &Opts::new()).unwrap()[0];
+    ///
+    /// assert_eq!(distrib.name(), "chrome");
+    /// assert!(distrib.electron_versions().contains(&20.0));
+    /// ```
+    pub fn electron_versions(&self) -> Vec<f32> {
+        if self.0 != "chrome" {
+            return Vec::new();
+        }
+
+        ELECTRON_VERSIONS
+            .iter()
+            .filter(|(_, chromium_version)| *chromium_version == self.1)
+            .map(|(electron_version, _)| *electron_version)
+            .collect()
+    }
+
+    fn version_detail(&self) -> Option<&'static caniuse::VersionDetail> {
+        // versions which come from desktop browsers with `mobile_to_desktop`
+        // can only be found in mapped data
+        [false, true]
+            .into_iter()
+            .filter_map(|mobile_to_desktop| caniuse::get_browser_stat(self.0, mobile_to_desktop))
+            .find_map(|(_, stat)| stat.version_list.iter().find(|v| v.version == self.1))
+    }
+}
+
+impl<'de> Deserialize<'de> for Distrib {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (name, version) = <(Cow<str>, Cow<str>)>::deserialize(deserializer)?;
+        find_bundled(&name, &version)
+            .map(|(name, version)| Self(name, version))
+            .ok_or_else(|| {
+                serde::de::Error::custom(format_args!(
+                    "unknown browser version: {} {}",
+                    name, version
+                ))
+            })
+    }
+}
+
+impl Display for Distrib {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} {}", self.0, self.1)
+    }
+}
+
+pub type QueryResult = Result<Vec<Distrib>, Error>;
+
+pub fn query(atom: QueryAtom, opts: &Opts) -> QueryResult {
+    match atom {
+        QueryAtom::Last {
+            count,
+            major,
+            name: Some(name),
+        } if name.eq_ignore_ascii_case("electron") => {
+            let count = count as usize;
+            if major {
+                last_n_electron_major::last_n_electron_major(count)
+            } else {
+                last_n_electron::last_n_electron(count)
+            }
+        }
+        QueryAtom::Last {
+            count,
+            major,
+            name: Some(name),
+        } if name.eq_ignore_ascii_case("node") => {
+            let count = count as usize;
+            if major {
+                last_n_node_major::last_n_node_major(count)
+            } else {
+                last_n_node::last_n_node(count)
+            }
+        }
+        QueryAtom::Last {
+            count,
+            major,
+            name: Some(name),
+        } => {
+            let count = count as usize;
+            if major {
+                last_n_x_major_browsers::last_n_x_major_browsers(count, name, opts)
+            } else {
+                last_n_x_browsers::last_n_x_browsers(count, name, opts)
+            }
+        }
+        QueryAtom::Last {
+            count,
+            major,
+            name: None,
+        } => {
+            let count = count as usize;
+            if major {
+                last_n_major_browsers::last_n_major_browsers(count, opts)
+            } else {
+                last_n_browsers::last_n_browsers(count, opts)
+            }
+        }
+        QueryAtom::Unreleased(Some(name)) if name.eq_ignore_ascii_case("electron") => {
+            unreleased_electron::unreleased_electron()
+        }
+        QueryAtom::Unreleased(Some(name)) => {
+            unreleased_x_browsers::unreleased_x_browsers(name, opts)
+        }
+        QueryAtom::Unreleased(None) => unreleased_browsers::unreleased_browsers(opts),
+        QueryAtom::Years(count) => years::years(count, opts),
+        QueryAtom::Since { year, month, day } => since::since(year, month, day, opts),
+        QueryAtom::Percentage {
+            comparator,
+            popularity,
+            stats: Stats::Global,
+        } => percentage::percentage(comparator, popularity),
+        QueryAtom::Percentage {
+            comparator,
+            popularity,
+            stats: Stats::Region(region),
+        } => percentage_by_region::percentage_by_region(comparator, popularity, region),
+        QueryAtom::Cover {
+            coverage,
+            stats: Stats::Global,
+        } => cover::cover(coverage),
+        QueryAtom::Cover {
+            coverage,
+            stats: Stats::Region(region),
+        } => cover_by_region::cover_by_region(coverage, region),
+        QueryAtom::Supports {
+            name,
+            without_prefix,
+        } => supports::supports(name, without_prefix),
+        QueryAtom::Electron(VersionRange::Bounded(from, to)) => {
+            electron_bounded_range::electron_bounded_range(from, to)
+        }
+        QueryAtom::Electron(VersionRange::Unbounded(comparator, version)) => {
+            electron_unbounded_range::electron_unbounded_range(comparator, version)
+        }
+        QueryAtom::Electron(VersionRange::Accurate(version)) => {
+            electron_accurate::electron_accurate(version)
+        }
+        QueryAtom::Node(VersionRange::Bounded(from, to)) => {
+            node_bounded_range::node_bounded_range(from, to)
+        }
+        QueryAtom::Node(VersionRange::Unbounded(comparator, version)) => {
+            node_unbounded_range::node_unbounded_range(comparator, version)
+        }
+        QueryAtom::Node(VersionRange::Accurate(version)) => {
+            node_accurate::node_accurate(version, opts)
+        }
+        QueryAtom::Browser(name, VersionRange::Bounded(from, to)) => {
+            browser_bounded_range::browser_bounded_range(name, from, to, opts)
+        }
+        QueryAtom::Browser(name, VersionRange::Unbounded(comparator, version)) => {
+            browser_unbounded_range::browser_unbounded_range(name, comparator, version, opts)
+        }
+        QueryAtom::Browser(name, VersionRange::Accurate(version)) => {
+            browser_accurate::browser_accurate(name, version, opts)
+        }
+        QueryAtom::FirefoxESR => firefox_esr::firefox_esr(),
+        QueryAtom::OperaMini => op_mini::op_mini(),
+        QueryAtom::CurrentNode => current_node::current_node(),
+        QueryAtom::MaintainedNode => maintained_node::maintained_node(opts),
+        QueryAtom::Phantom(is_later_version) => phantom::phantom(is_later_version),
+        QueryAtom::BrowserslistConfig => browserslist_config::browserslist_config(opts),
+        QueryAtom::Defaults => defaults::defaults(opts),
+        QueryAtom::Dead => dead::dead(opts),
+        QueryAtom::Unknown(query) => Err(Error::UnknownQuery(query.into())),
+    }
+}
+
+/// Queries which a query atom is defined by.
+pub enum Expansion {
+    /// Queries built in this library, such as those of `defaults`.
+    Builtin(&'static [&'static str]),
+    /// Queries loaded from configuration.
+    Config(Vec<String>),
+}
+
+/// Return queries which the atom expands to,
+/// or `None` if the atom isn't defined by other queries.
+pub fn expand(atom: &QueryAtom, opts: &Opts) -> Result<Option<Expansion>, Error> {
+    match atom {
+        QueryAtom::BrowserslistConfig => browserslist_config::browserslist_config_queries(opts)
+            .map(|queries| Some(Expansion::Config(queries))),
+        _ => Ok(expand_builtin(atom).map(Expansion::Builtin)),
+    }
+}
+
+/// Return queries built in this library which the atom expands to,
+/// such as those of `defaults` and `dead`.
+pub fn expand_builtin(atom: &QueryAtom) -> Option<&'static [&'static str]> {
+    match atom {
+        QueryAtom::Defaults => Some(defaults::DEFAULTS),
+        QueryAtom::Dead => Some(dead::DEAD),
+        _ => None,
+    }
+}
+
+#[inline]
+pub fn should_filter_android(name: &str, mobile_to_desktop: bool) -> bool {
+    name == "android" && !mobile_to_desktop
+}
+
+pub fn count_android_filter(count: usize, mobile_to_desktop: bool) -> usize {
+    if count == 0 {
+        return 0;
+    }
+    let last_released = caniuse::get_browser_stat("android", mobile_to_desktop)
+        .and_then(|(_, stat)| {
+            stat.version_list
+                .iter()
+                .rfind(|version| version.release_date.is_some())
+        })
+        .and_then(|version| version.version.parse::<f32>().ok())
+        .unwrap_or_default();
+    let diff = last_released - caniuse::ANDROID_EVERGREEN_FIRST - (count as f32);
+    if diff > 0.0 {
+        1
+    } else {
+        (1.0 - diff) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resolve;
+    use test_case::test_case;
+
+    #[test_case("defaults, op_mini all, safari tp"; "browsers")]
+    #[test_case("node >= 16, electron 20"; "node and electron")]
+    #[test_case("last 2 and_chr versions, last 2 android versions"; "mobile to desktop")]
+    fn deserialize_bundled(query: &str) {
+        let distribs = resolve([query], Opts::new().mobile_to_desktop(true)).unwrap();
+        let json = serde_json::to_string(&distribs).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Vec<Distrib>>(&json).unwrap(),
+            distribs
+        );
+    }
+
+    #[test]
+    fn mobile_to_desktop_detail() {
+        let mobile = &resolve(["and_chr 100"], Opts::new().mobile_to_desktop(true)).unwrap()[0];
+        let desktop = &resolve(["chrome 100"], &Opts::new()).unwrap()[0];
+        assert!(mobile.release_date().is_some());
+        assert_eq!(mobile.release_date(), desktop.release_date());
+        assert_eq!(mobile.global_usage(), desktop.global_usage());
+    }
+
+    #[test_case(r#"["yuru", "1.0"]"#; "unknown browser")]
+    #[test_case(r#"["ie", "999"]"#; "unknown version")]
+    #[test_case(r#"["node", "18.0.0-nightly"]"#; "unknown node version")]
+    fn deserialize_unknown(json: &str) {
+        assert!(serde_json::from_str::<Distrib>(json).is_err());
+    }
+}
@@ -1,5 +1,6 @@
 use super::{Distrib, QueryResult};
 use crate::{data::node::NODE_VERSIONS, error::Error, opts::Opts};
+use alloc::{string::ToString, vec};
 
 pub(super) fn node_accurate(version: &str, opts: &Opts) -> QueryResult {
     let distribs = NODE_VERSIONS
@@ -1,8 +1,20 @@
 use super::{Distrib, QueryResult};
-use crate::{data::node::NODE_VERSIONS, semver::loose_compare};
-use std::cmp::Ordering;
+use crate::{
+    data::node::NODE_VERSIONS,
+    error::Error,
+    semver::{loose_compare, Version},
+};
+use alloc::string::ToString;
+use core::cmp::Ordering;
 
 pub(super) fn node_bounded_range(from: &str, to: &str) -> QueryResult {
+    if let Some(version) = [from, to]
+        .into_iter()
+        .find(|version| version.parse::<Version>().is_err())
+    {
+        return Err(Error::UnknownNodejsVersion(version.to_string()));
+    }
+
     let distribs = NODE_VERSIONS
         .iter()
         .filter(|version| {
@@ -41,6 +53,10 @@ mod tests {
         "node 6-8.a", Error::Nom(String::from("a"));
         "malformed version"
     )]
+    #[test_case(
+        "node 0-99999999999999999999", Error::UnknownNodejsVersion(String::from("99999999999999999999"));
+        "overflow"
+    )]
     fn invalid(query: &str, error: Error) {
         assert_eq!(should_failed(query, &Opts::new()), error);
     }
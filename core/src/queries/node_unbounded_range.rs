@@ -1,12 +1,16 @@
 use super::{Distrib, QueryResult};
-use crate::{data::node::NODE_VERSIONS, parser::Comparator, semver::compare};
-use std::cmp::Ordering;
+use crate::{data::node::NODE_VERSIONS, error::Error, parser::Comparator, semver::Version};
+use alloc::string::ToString;
+use core::cmp::Ordering;
 
 pub(super) fn node_unbounded_range(comparator: Comparator, version: &str) -> QueryResult {
+    let version = version
+        .parse::<Version>()
+        .map_err(|_| Error::UnknownNodejsVersion(version.to_string()))?;
     let distribs = NODE_VERSIONS
         .iter()
         .filter(|v| {
-            let ord = compare(v, version);
+            let ord = v.parse::<Version>().unwrap_or_default().cmp(&version);
             match comparator {
                 Comparator::Greater => matches!(ord, Ordering::Greater),
                 Comparator::Less => matches!(ord, Ordering::Less),
@@ -44,6 +48,14 @@ mod tests {
         "node < 8.a", Error::Nom(String::from("a"));
         "malformed version"
     )]
+    #[test_case(
+        "node > 99999999999.0", Error::UnknownNodejsVersion(String::from("99999999999.0"));
+        "overflow major"
+    )]
+    #[test_case(
+        "node >= 99999999999999999999", Error::UnknownNodejsVersion(String::from("99999999999999999999"));
+        "overflow major only"
+    )]
     fn invalid(query: &str, error: Error) {
         assert_eq!(should_failed(query, &Opts::new()), error);
     }
@@ -1,4 +1,5 @@
 use super::{Distrib, QueryResult};
+use alloc::vec;
 
 pub(super) fn op_mini() -> QueryResult {
     Ok(vec![Distrib::new("op_mini", "all")])
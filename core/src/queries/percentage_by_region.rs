@@ -1,18 +1,13 @@
 use super::{Distrib, QueryResult};
 use crate::{data::caniuse::region::get_usage_by_region, error::Error, parser::Comparator};
+use alloc::string::ToString;
 
 pub(super) fn percentage_by_region(
     comparator: Comparator,
     popularity: f32,
     region: &str,
 ) -> QueryResult {
-    let normalized_region = if region.len() == 2 {
-        region.to_uppercase()
-    } else {
-        region.to_lowercase()
-    };
-
-    if let Some(region_data) = get_usage_by_region(&normalized_region) {
+    if let Some(region_data) = get_usage_by_region(region) {
         let distribs = region_data
             .iter()
             .filter(|(_, _, usage)| match comparator {
@@ -21,7 +16,7 @@ pub(super) fn percentage_by_region(
                 Comparator::GreaterOrEqual => *usage >= popularity,
                 Comparator::LessOrEqual => *usage <= popularity,
             })
-            .map(|(name, version, _)| Distrib::new(name, *version))
+            .map(|(name, version, _)| Distrib::new(name, version))
             .collect();
         Ok(distribs)
     } else {
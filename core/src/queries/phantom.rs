@@ -1,4 +1,5 @@
 use super::{Distrib, QueryResult};
+use alloc::vec;
 
 pub(super) fn phantom(is_later_version: bool) -> QueryResult {
     let version = if is_later_version { "6" } else { "5" };
@@ -4,6 +4,7 @@ use crate::{
     error::Error,
     opts::Opts,
 };
+use alloc::format;
 use chrono::{LocalResult, TimeZone, Utc};
 
 pub(super) fn since(year: i32, month: u32, day: u32, opts: &Opts) -> QueryResult {
@@ -19,7 +20,7 @@ pub(super) fn since(year: i32, month: u32, day: u32, opts: &Opts) -> QueryResult
             stat.version_list
                 .iter()
                 .filter(|version| matches!(version.release_date, Some(date) if date >= time))
-                .map(|version| Distrib::new(name, &*version.version))
+                .map(|version| Distrib::new(name, version.version))
         })
         .collect();
     Ok(distribs)
@@ -28,7 +29,7 @@ pub(super) fn since(year: i32, month: u32, day: u32, opts: &Opts) -> QueryResult
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test::run_compare;
+    use crate::test::{run_compare, should_failed};
     use test_case::test_case;
 
     #[test_case("since 2017"; "year only")]
@@ -39,4 +40,14 @@ mod tests {
     fn valid(query: &str) {
         run_compare(query, &Opts::new());
     }
+
+    #[test_case("since 2147483647"; "year out of range")]
+    #[test_case("since 2017-13"; "invalid month")]
+    #[test_case("since 2017-02-30"; "invalid day")]
+    fn invalid(query: &str) {
+        assert!(matches!(
+            should_failed(query, &Opts::new()),
+            Error::InvalidDate(..)
+        ));
+    }
 }
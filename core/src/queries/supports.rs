@@ -0,0 +1,184 @@
+use super::{Distrib, QueryResult};
+use crate::{
+    data::{
+        caniuse::{features::get_feature_stat, get_browser_stat},
+        mdn::get_mdn_feature,
+        node::NODE_VERSIONS,
+    },
+    error::Error,
+    version::BrowserVersion,
+};
+use alloc::{string::ToString, vec::Vec};
+
+pub(super) fn supports(name: &str, without_prefix: bool) -> QueryResult {
+    if let Some(path) = name
+        .get(..4)
+        .filter(|prefix| prefix.eq_ignore_ascii_case("mdn:"))
+        .map(|_| &name[4..])
+    {
+        return supports_mdn(name, path, without_prefix);
+    }
+
+    if let Some(feature) = get_feature_stat(name) {
+        let distribs = feature
+            .iter()
+            .filter(|(_, _, prefixed, _)| !(without_prefix && *prefixed))
+            .map(|(name, version, ..)| Distrib::new(name, version))
+            .collect();
+        Ok(distribs)
+    } else {
+        Err(Error::UnknownBrowserFeature(name.to_string()))
+    }
+}
+
+/// Query feature from MDN browser-compat-data, whose path is like `api.Navigator.share`.
+fn supports_mdn(name: &str, path: &str, without_prefix: bool) -> QueryResult {
+    let statements =
+        get_mdn_feature(path).ok_or_else(|| Error::UnknownBrowserFeature(name.to_string()))?;
+
+    let mut distribs = Vec::<Distrib>::new();
+    for (browser, version_added, version_removed, prefixed) in statements {
+        if without_prefix && *prefixed {
+            continue;
+        }
+        let versions = if *browser == "node" {
+            NODE_VERSIONS
+                .iter()
+                .map(|version| ("node", *version))
+                .collect()
+        } else if let Some((name, stat)) = get_browser_stat(browser, false) {
+            stat.version_list
+                .iter()
+                .map(|version| (name, version.version))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        for (name, version) in versions {
+            let distrib = Distrib::new(name, version);
+            if is_supported(version, version_added, *version_removed)
+                && !distribs.contains(&distrib)
+            {
+                distribs.push(distrib);
+            }
+        }
+    }
+    Ok(distribs)
+}
+
+/// Check if version is between version added (inclusive) and version removed (exclusive).
+/// Version `preview` in MDN browser-compat-data means preview versions like Safari Technology Preview.
+fn is_supported(version: &str, version_added: &str, version_removed: Option<&str>) -> bool {
+    fn numeric(version: &str) -> Option<(u32, u32, u32)> {
+        version
+            .parse::<BrowserVersion>()
+            .ok()?
+            .lower_bound()
+            .map(|version| (version.major(), version.minor(), version.patch()))
+    }
+
+    if version == "TP" {
+        return (version_added == "preview" || numeric(version_added).is_some())
+            && version_removed.is_none();
+    }
+    let (Some(version), Some(version_added)) = (numeric(version), numeric(version_added)) else {
+        return false;
+    };
+    version >= version_added
+        && match version_removed.and_then(numeric) {
+            Some(version_removed) => version < version_removed,
+            None => true,
+        }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        opts::Opts,
+        prefix_requirements, resolve,
+        test::{run_compare, should_failed},
+    };
+    use alloc::{format, string::ToString};
+    use test_case::test_case;
+
+    #[test_case("supports objectrtc"; "case 1")]
+    #[test_case("supports    rtcpeerconnection"; "case 2")]
+    #[test_case("supports        arrow-functions"; "case 3")]
+    fn valid(query: &str) {
+        run_compare(query, &Opts::new());
+    }
+
+    #[test_case("supports mdn:api.Navigator.share"; "api")]
+    #[test_case("supports mdn:javascript.builtins.Array.at"; "javascript")]
+    #[test_case("supports MDN:css.properties.-webkit-box-reflect"; "case insensitive")]
+    fn mdn(query: &str) {
+        let distribs = resolve([query], &Opts::new()).unwrap();
+        assert!(!distribs.is_empty());
+    }
+
+    #[test_case("css-appearance"; "prefixed")]
+    #[test_case("es6"; "unprefixed")]
+    fn without_prefix(feature: &str) {
+        let all = resolve([format!("supports {feature}")], &Opts::new()).unwrap();
+        let unprefixed = resolve(
+            [format!("supports {feature} WITHOUT  prefix")],
+            &Opts::new(),
+        )
+        .unwrap();
+        assert!(unprefixed.iter().all(|distrib| all.contains(distrib)));
+        assert_eq!(
+            unprefixed.len() < all.len(),
+            prefix_requirements(feature, &all).unwrap().needs_prefix()
+        );
+    }
+
+    #[test]
+    fn mdn_versions() {
+        let distribs = resolve(["supports mdn:javascript.builtins.Array.at"], &Opts::new())
+            .unwrap()
+            .into_iter()
+            .map(|distrib| distrib.to_string())
+            .collect::<Vec<_>>();
+        assert!(distribs.iter().any(|distrib| distrib == "chrome 92"));
+        assert!(!distribs.iter().any(|distrib| distrib == "chrome 91"));
+        assert!(distribs.iter().any(|distrib| distrib == "node 18.0.0"));
+        assert!(!distribs.iter().any(|distrib| distrib == "node 14.0.0"));
+        assert!(distribs.iter().any(|distrib| distrib == "ios_saf 17.0"));
+        assert!(!distribs
+            .iter()
+            .any(|distrib| distrib == "ios_saf 15.2-15.3"));
+        assert!(distribs.iter().any(|distrib| distrib == "safari TP"));
+    }
+
+    #[test_case("15.4", "15.4", None, true)]
+    #[test_case("15.2-15.3", "15.3", None, false)]
+    #[test_case("16.0", "15.4", Some("16"), false)]
+    #[test_case("TP", "preview", None, true)]
+    #[test_case("17.0", "preview", None, false)]
+    #[test_case("TP", "10", Some("preview"), false)]
+    #[test_case("TP", "10", None, true)]
+    #[test_case("TP", "false", None, false)]
+    #[test_case("TP", "", None, false)]
+    #[test_case("16.14.0", "16.6.0", None, true)]
+    fn statement(version: &str, added: &str, removed: Option<&str>, expected: bool) {
+        assert_eq!(is_supported(version, added, removed), expected);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            should_failed("supports xxxyyyzzz", &Opts::new()),
+            Error::UnknownBrowserFeature(String::from("xxxyyyzzz"))
+        );
+        assert_eq!(
+            should_failed("supports mdn:api.Xxx.yyy", &Opts::new()),
+            Error::UnknownBrowserFeature(String::from("mdn:api.Xxx.yyy"))
+        );
+        assert_eq!(
+            should_failed("supports es6 without", &Opts::new()),
+            Error::Nom(String::from(" without"))
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use super::QueryResult;
+use alloc::vec;
 
 pub(super) fn unreleased_electron() -> QueryResult {
     Ok(vec![])
@@ -1,5 +1,6 @@
 use super::{Distrib, QueryResult};
 use crate::{data::caniuse::get_browser_stat, error::Error, opts::Opts};
+use alloc::string::ToString;
 
 pub(super) fn unreleased_x_browsers(name: &str, opts: &Opts) -> QueryResult {
     let (name, stat) = get_browser_stat(name, opts.mobile_to_desktop)
@@ -8,7 +9,7 @@ pub(super) fn unreleased_x_browsers(name: &str, opts: &Opts) -> QueryResult {
         .version_list
         .iter()
         .filter(|version| version.release_date.is_none())
-        .map(|version| Distrib::new(name, &*version.version))
+        .map(|version| Distrib::new(name, version.version))
         .collect();
     Ok(distribs)
 }
@@ -0,0 +1,62 @@
+use super::{Distrib, QueryResult};
+use crate::{
+    data::caniuse::{get_browser_stat, CANIUSE_BROWSERS},
+    opts::Opts,
+};
+
+const ONE_YEAR_IN_SECONDS: f64 = 365.259641 * 24.0 * 60.0 * 60.0;
+
+pub(super) fn years(count: f64, opts: &Opts) -> QueryResult {
+    // compute in floating point, so huge or non-finite counts can't overflow
+    let time = opts.current_timestamp()? as f64 - count * ONE_YEAR_IN_SECONDS;
+
+    let distribs = CANIUSE_BROWSERS
+        .keys()
+        .filter_map(|name| get_browser_stat(name, opts.mobile_to_desktop))
+        .flat_map(|(name, stat)| {
+            stat.version_list
+                .iter()
+                .filter(|version| matches!(version.release_date, Some(date) if date as f64 >= time))
+                .map(|version| Distrib::new(name, version.version))
+        })
+        .collect();
+    Ok(distribs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::run_compare;
+    use test_case::test_case;
+
+    #[test_case("last 2 years"; "basic")]
+    #[test_case("last 1 year"; "one year")]
+    #[test_case("last 1.4 years"; "year fraction")]
+    #[test_case("Last 5 Years"; "case insensitive")]
+    #[test_case("last    2     years"; "more spaces")]
+    fn valid(query: &str) {
+        run_compare(query, &Opts::new());
+    }
+
+    #[test]
+    fn respect_now() {
+        // 2015-01-01T00:00:00Z and 2020-01-01T00:00:00Z
+        let past = crate::resolve(["last 1 year"], Opts::new().now(1420070400)).unwrap();
+        let recent = crate::resolve(["last 1 year"], Opts::new().now(1577836800)).unwrap();
+        assert!(!past.is_empty());
+        assert!(!recent.is_empty());
+        assert_ne!(past, recent);
+
+        // browsers released after the given time are excluded
+        let since = crate::resolve(["since 2013"], &Opts::new()).unwrap();
+        assert!(past.iter().all(|distrib| since.contains(distrib)));
+        assert!(recent.iter().all(|distrib| since.contains(distrib)));
+        assert!(past.len() < since.len());
+    }
+
+    #[test_case("last 1e400 years"; "infinite")]
+    #[test_case("last 65535000000000 years"; "overflow")]
+    fn huge(query: &str) {
+        assert!(crate::resolve([query], &Opts::new()).is_ok());
+    }
+}
@@ -1,4 +1,4 @@
-use std::{cmp::Ordering, num::ParseIntError, str::FromStr};
+use core::{cmp::Ordering, num::ParseIntError, str::FromStr};
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Default, Debug, Clone)]
 pub(crate) struct Version(u32, u32, u32);
@@ -33,21 +33,15 @@ impl FromStr for Version {
     }
 }
 
-pub(crate) fn compare(a: &str, b: &str) -> Ordering {
-    a.parse::<Version>()
-        .unwrap_or_default()
-        .cmp(&b.parse().unwrap_or_default())
-}
-
 pub(crate) fn loose_compare(a: &str, b: &str) -> Ordering {
     a.split('.')
         .take(2)
         .zip(b.split('.').take(2))
         .fold(Ordering::Equal, |ord, (a, b)| {
             if ord == Ordering::Equal {
-                a.parse::<i32>()
+                a.parse::<u32>()
                     .unwrap_or_default()
-                    .cmp(&b.parse::<i32>().unwrap_or_default())
+                    .cmp(&b.parse::<u32>().unwrap_or_default())
             } else {
                 ord
             }
@@ -0,0 +1,186 @@
+use super::lowest_versions;
+use crate::{
+    queries::Distrib,
+    version::{BrowserVersion, VersionNumber},
+};
+use serde::{Deserialize, Serialize};
+
+/// Targets in the format of `targets` option of Babel's `@babel/preset-env` and swc's `env`.
+///
+/// Each field holds the lowest version of that target,
+/// which is either a full version like `87.0.0` or `TP` for Safari Technology Preview.
+/// When serializing, missing targets are omitted.
+///
+/// Browser names are mapped as below, like what Babel does:
+///
+/// | Browserslist          | Target         |
+/// | --------------------- | -------------- |
+/// | `and_chr`, `chrome`   | `chrome`       |
+/// | `and_ff`, `firefox`   | `firefox`      |
+/// | `android`             | `android`      |
+/// | `edge`                | `edge`         |
+/// | `ie`, `ie_mob`        | `ie`           |
+/// | `ios_saf`             | `ios`          |
+/// | `node`                | `node`         |
+/// | `op_mob`              | `opera_mobile` |
+/// | `opera`               | `opera`        |
+/// | `safari`              | `safari`       |
+/// | `samsung`             | `samsung`      |
+///
+/// Other browsers, such as `op_mini` and `kaios`, aren't supported by Babel, so they're skipped.
+/// Electron versions are resolved as Chrome versions by browserslist,
+/// so they're converted to `chrome` target.
+///
+/// ```
+/// use browserslist::{resolve, BabelTargets, Opts};
+///
+/// let distribs = resolve(["ios_saf 15.2-15.3, ios_saf 16.0, chrome 100"], &Opts::new()).unwrap();
+/// let targets = BabelTargets::from_distribs(&distribs);
+///
+/// assert_eq!(
+///     serde_json::to_string(&targets).unwrap(),
+///     r#"{"chrome":"100.0.0","ios":"15.2.0"}"#
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BabelTargets {
+    /// Android WebView.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub android: Option<BrowserVersion>,
+    /// Chrome, Chrome for Android and Electron.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chrome: Option<BrowserVersion>,
+    /// Edge.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edge: Option<BrowserVersion>,
+    /// Firefox and Firefox for Android.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub firefox: Option<BrowserVersion>,
+    /// Internet Explorer and IE Mobile.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ie: Option<BrowserVersion>,
+    /// Safari on iOS.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ios: Option<BrowserVersion>,
+    /// Node.js.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<BrowserVersion>,
+    /// Opera.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opera: Option<BrowserVersion>,
+    /// Opera Mobile.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opera_mobile: Option<BrowserVersion>,
+    /// Safari on desktop.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub safari: Option<BrowserVersion>,
+    /// Samsung Internet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub samsung: Option<BrowserVersion>,
+}
+
+impl BabelTargets {
+    /// Convert resolved distribs to targets, using the lowest version of each target.
+    pub fn from_distribs(distribs: &[Distrib]) -> Self {
+        let mut targets = Self::default();
+        for (target, version) in lowest_versions(distribs, target_of) {
+            // like Babel, versions are always written in full form
+            let version = match version {
+                BrowserVersion::Single(version) => BrowserVersion::Single(VersionNumber::new(
+                    version.major(),
+                    version.minor(),
+                    version.patch(),
+                )),
+                version => version,
+            };
+            let field = match target {
+                "android" => &mut targets.android,
+                "chrome" => &mut targets.chrome,
+                "edge" => &mut targets.edge,
+                "firefox" => &mut targets.firefox,
+                "ie" => &mut targets.ie,
+                "ios" => &mut targets.ios,
+                "node" => &mut targets.node,
+                "opera" => &mut targets.opera,
+                "opera_mobile" => &mut targets.opera_mobile,
+                "safari" => &mut targets.safari,
+                "samsung" => &mut targets.samsung,
+                _ => continue,
+            };
+            *field = Some(version);
+        }
+        targets
+    }
+}
+
+fn target_of(name: &str) -> Option<&'static str> {
+    match name {
+        "and_chr" | "chrome" => Some("chrome"),
+        "and_ff" | "firefox" => Some("firefox"),
+        "android" => Some("android"),
+        "edge" => Some("edge"),
+        "ie" | "ie_mob" => Some("ie"),
+        "ios_saf" => Some("ios"),
+        "node" => Some("node"),
+        "op_mob" => Some("opera_mobile"),
+        "opera" => Some("opera"),
+        "safari" => Some("safari"),
+        "samsung" => Some("samsung"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{opts::Opts, resolve};
+    use alloc::string::ToString;
+    use test_case::test_case;
+
+    fn convert(query: &str) -> BabelTargets {
+        BabelTargets::from_distribs(&resolve([query], &Opts::new()).unwrap())
+    }
+
+    #[test_case("last 1 and_chr version", "chrome"; "chrome for android")]
+    #[test_case("last 1 and_ff version", "firefox"; "firefox for android")]
+    #[test_case("ie_mob 11", "ie"; "ie mobile")]
+    #[test_case("ios_saf 16.0", "ios"; "ios safari")]
+    #[test_case("op_mob 12.1", "opera_mobile"; "opera mobile")]
+    #[test_case("last 1 samsung version", "samsung"; "samsung internet")]
+    #[test_case("node 18.0.0", "node"; "node")]
+    #[test_case("electron 20", "chrome"; "electron")]
+    fn mapping(query: &str, target: &str) {
+        let json = serde_json::to_value(convert(query)).unwrap();
+        assert_eq!(json.as_object().unwrap().len(), 1);
+        assert!(json[target].is_string());
+    }
+
+    #[test]
+    fn lowest_version() {
+        let targets = convert(
+            "chrome 101, chrome 99, last 1 and_chr version, \
+            ios_saf 15.2-15.3, ios_saf 14.0-14.4, node 16.4.1, node 18.0.0",
+        );
+        assert_eq!(targets.chrome.unwrap().to_string(), "99.0.0");
+        assert_eq!(targets.ios.unwrap().to_string(), "14.0.0");
+        assert_eq!(targets.node.unwrap().to_string(), "16.4.1");
+        assert_eq!(targets.opera_mobile, None);
+    }
+
+    #[test]
+    fn technology_preview() {
+        let targets = convert("safari tp");
+        assert_eq!(targets.safari, Some(BrowserVersion::TechnologyPreview));
+
+        let targets = convert("safari tp, safari 17.0");
+        assert_eq!(targets.safari.unwrap().to_string(), "17.0.0");
+    }
+
+    #[test]
+    fn unsupported() {
+        assert_eq!(
+            convert("op_mini all, kaios 2.5, bb 10"),
+            BabelTargets::default()
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use super::lowest_versions;
+use crate::{
+    queries::Distrib,
+    version::{BrowserVersion, VersionNumber},
+};
+use alloc::{collections::BTreeSet, format, string::String, vec::Vec};
+use core::fmt;
+
+/// Targets in the format of esbuild's `--target` option, like `chrome87,firefox78,safari14`.
+///
+/// Each target uses the lowest version of that engine.
+/// Browser names are mapped to esbuild engines as below:
+///
+/// | Browserslist          | esbuild   |
+/// | --------------------- | --------- |
+/// | `and_chr`, `chrome`   | `chrome`  |
+/// | `and_ff`, `firefox`   | `firefox` |
+/// | `edge`                | `edge`    |
+/// | `ie`, `ie_mob`        | `ie`      |
+/// | `ios_saf`             | `ios`     |
+/// | `node`                | `node`    |
+/// | `opera`               | `opera`   |
+/// | `safari`              | `safari`  |
+///
+/// Other browsers, such as `op_mini`, `kaios` and `bb`, can't be expressed in esbuild,
+/// and Safari Technology Preview doesn't have a version number,
+/// so they're reported by [`EsbuildTargets::unsupported_browsers`] instead of being dropped silently.
+///
+/// ```
+/// use browserslist::{resolve, EsbuildTargets, Opts};
+///
+/// let distribs = resolve(["chrome 100-101, ios_saf 15.2-15.3, op_mini all"], &Opts::new()).unwrap();
+/// let targets = EsbuildTargets::from_distribs(&distribs);
+///
+/// assert_eq!(targets.to_string(), "chrome100,ios15.2");
+/// assert_eq!(targets.unsupported_browsers().collect::<Vec<_>>(), ["op_mini"]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EsbuildTargets {
+    targets: Vec<String>,
+    unsupported: BTreeSet<&'static str>,
+}
+
+impl EsbuildTargets {
+    /// Convert resolved distribs to esbuild targets.
+    pub fn from_distribs(distribs: &[Distrib]) -> Self {
+        let targets = lowest_versions(distribs, engine_of)
+            .into_iter()
+            .filter_map(|(engine, version)| match version {
+                BrowserVersion::Single(version) => {
+                    Some(format!("{}{}", engine, format_version(&version)))
+                }
+                _ => None,
+            })
+            .collect();
+
+        // versions without number are reported
+        // even if other versions of the same browser are in targets
+        let unsupported = distribs
+            .iter()
+            .filter(|distrib| {
+                engine_of(distrib.name()).is_none()
+                    || distrib.browser_version().lower_bound().is_none()
+            })
+            .map(|distrib| distrib.name())
+            .collect();
+
+        Self {
+            targets,
+            unsupported,
+        }
+    }
+
+    /// Return targets, each of which is like `chrome87`.
+    pub fn targets(&self) -> &[String] {
+        &self.targets
+    }
+
+    /// Return Browserslist names of browsers which can't be expressed in esbuild targets,
+    /// ordered by name.
+    pub fn unsupported_browsers(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.unsupported.iter().copied()
+    }
+}
+
+impl fmt::Display for EsbuildTargets {
+    /// Format as the value of esbuild's `--target` option, separated by commas.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.targets.join(","))
+    }
+}
+
+fn engine_of(name: &str) -> Option<&'static str> {
+    match name {
+        "and_chr" | "chrome" => Some("chrome"),
+        "and_ff" | "firefox" => Some("firefox"),
+        "edge" => Some("edge"),
+        "ie" | "ie_mob" => Some("ie"),
+        "ios_saf" => Some("ios"),
+        "node" => Some("node"),
+        "opera" => Some("opera"),
+        "safari" => Some("safari"),
+        _ => None,
+    }
+}
+
+/// Format version without trailing zero components and prerelease label,
+/// since esbuild only accepts numeric versions.
+fn format_version(version: &VersionNumber) -> String {
+    match (version.minor(), version.patch()) {
+        (0, 0) => format!("{}", version.major()),
+        (minor, 0) => format!("{}.{}", version.major(), minor),
+        (minor, patch) => format!("{}.{}.{}", version.major(), minor, patch),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{opts::Opts, resolve};
+    use alloc::string::ToString;
+    use test_case::test_case;
+
+    fn convert(query: &str) -> EsbuildTargets {
+        EsbuildTargets::from_distribs(&resolve([query], &Opts::new()).unwrap())
+    }
+
+    #[test_case("last 1 and_chr version", "chrome"; "chrome for android")]
+    #[test_case("last 1 and_ff version", "firefox"; "firefox for android")]
+    #[test_case("ie_mob 11", "ie"; "ie mobile")]
+    #[test_case("ios_saf 16.0", "ios"; "ios safari")]
+    #[test_case("node 18.0.0", "node"; "node")]
+    fn mapping(query: &str, engine: &str) {
+        let targets = convert(query);
+        assert_eq!(targets.targets().len(), 1);
+        assert_eq!(
+            targets.targets()[0].trim_end_matches(|c: char| c.is_ascii_digit() || c == '.'),
+            engine
+        );
+    }
+
+    #[test]
+    fn lowest_version() {
+        let targets = convert(
+            "chrome 101, chrome 100, edge 100, ios_saf 15.2-15.3, ios_saf 14.0-14.4, \
+            node 16.4.1, node 18.0.0, safari tp, safari 15.4",
+        );
+        assert_eq!(
+            targets.targets(),
+            ["chrome100", "edge100", "ios14", "node16.4.1", "safari15.4"]
+        );
+        assert_eq!(
+            targets.unsupported_browsers().collect::<Vec<_>>(),
+            ["safari"]
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        let targets = convert("op_mini all, kaios 2.5, bb 10, samsung 22, safari tp, firefox 100");
+        assert_eq!(targets.to_string(), "firefox100");
+        assert_eq!(
+            targets.unsupported_browsers().collect::<Vec<_>>(),
+            ["bb", "kaios", "op_mini", "safari", "samsung"]
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use super::lowest_versions;
+use crate::{
+    queries::Distrib,
+    version::{BrowserVersion, VersionNumber},
+};
+use serde::{Deserialize, Serialize};
+
+/// Targets in the format of Lightning CSS's `Browsers` struct.
+///
+/// Each field holds the lowest version of that engine,
+/// packed as `major << 16 | minor << 8 | patch`.
+/// Every component takes 8 bits, so components greater than `255` are clamped to `255`.
+///
+/// Browser names are mapped as below, like what Lightning CSS does:
+///
+/// | Browserslist          | Field     |
+/// | --------------------- | --------- |
+/// | `android`             | `android` |
+/// | `and_chr`, `chrome`   | `chrome`  |
+/// | `edge`                | `edge`    |
+/// | `and_ff`, `firefox`   | `firefox` |
+/// | `ie`                  | `ie`      |
+/// | `ios_saf`             | `ios_saf` |
+/// | `op_mob`, `opera`     | `opera`   |
+/// | `safari`              | `safari`  |
+/// | `samsung`             | `samsung` |
+///
+/// Other browsers and Node.js are skipped.
+/// Joined versions like `15.2-15.3` use their lower bounds.
+/// `TP` and `all` don't have version numbers,
+/// so a browser which only has those versions is left as `None`.
+///
+/// ```
+/// use browserslist::{resolve, LightningCssTargets, Opts};
+///
+/// let distribs = resolve(["chrome 100-101, ios_saf 15.2-15.3, safari tp"], &Opts::new()).unwrap();
+/// let targets = LightningCssTargets::from_distribs(&distribs);
+///
+/// assert_eq!(targets.chrome, Some(100 << 16));
+/// assert_eq!(targets.ios_saf, Some(15 << 16 | 2 << 8));
+/// assert_eq!(targets.safari, None);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LightningCssTargets {
+    /// Android WebView.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub android: Option<u32>,
+    /// Chrome and Chrome for Android.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chrome: Option<u32>,
+    /// Edge.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edge: Option<u32>,
+    /// Firefox and Firefox for Android.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub firefox: Option<u32>,
+    /// Internet Explorer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ie: Option<u32>,
+    /// Safari on iOS.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ios_saf: Option<u32>,
+    /// Opera and Opera Mobile.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opera: Option<u32>,
+    /// Safari on desktop.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub safari: Option<u32>,
+    /// Samsung Internet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub samsung: Option<u32>,
+}
+
+impl LightningCssTargets {
+    /// Convert resolved distribs to targets, using the lowest version of each engine.
+    pub fn from_distribs(distribs: &[Distrib]) -> Self {
+        let mut targets = Self::default();
+        for (engine, version) in lowest_versions(distribs, engine_of) {
+            let version = match version {
+                BrowserVersion::Single(version) => pack(&version),
+                _ => continue,
+            };
+            let field = match engine {
+                "android" => &mut targets.android,
+                "chrome" => &mut targets.chrome,
+                "edge" => &mut targets.edge,
+                "firefox" => &mut targets.firefox,
+                "ie" => &mut targets.ie,
+                "ios_saf" => &mut targets.ios_saf,
+                "opera" => &mut targets.opera,
+                "safari" => &mut targets.safari,
+                "samsung" => &mut targets.samsung,
+                _ => continue,
+            };
+            *field = Some(version);
+        }
+        targets
+    }
+}
+
+fn engine_of(name: &str) -> Option<&'static str> {
+    match name {
+        "android" => Some("android"),
+        "and_chr" | "chrome" => Some("chrome"),
+        "edge" => Some("edge"),
+        "and_ff" | "firefox" => Some("firefox"),
+        "ie" => Some("ie"),
+        "ios_saf" => Some("ios_saf"),
+        "op_mob" | "opera" => Some("opera"),
+        "safari" => Some("safari"),
+        "samsung" => Some("samsung"),
+        _ => None,
+    }
+}
+
+fn pack(version: &VersionNumber) -> u32 {
+    version.major().min(255) << 16 | version.minor().min(255) << 8 | version.patch().min(255)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{opts::Opts, resolve};
+    use test_case::test_case;
+
+    fn convert(query: &str) -> LightningCssTargets {
+        LightningCssTargets::from_distribs(&resolve([query], &Opts::new()).unwrap())
+    }
+
+    #[test_case("last 1 and_chr version", "chrome"; "chrome for android")]
+    #[test_case("last 1 and_ff version", "firefox"; "firefox for android")]
+    #[test_case("android 4.4", "android"; "android webview")]
+    #[test_case("ios_saf 16.0", "ios_saf"; "ios safari")]
+    #[test_case("op_mob 12.1", "opera"; "opera mobile")]
+    #[test_case("last 1 samsung version", "samsung"; "samsung internet")]
+    fn mapping(query: &str, field: &str) {
+        let json = serde_json::to_value(convert(query)).unwrap();
+        assert_eq!(json.as_object().unwrap().len(), 1);
+        assert!(json[field].is_u64());
+    }
+
+    #[test]
+    fn packing() {
+        let targets =
+            convert("android 4.4.3-4.4.4, chrome 101, chrome 100, safari 15.4, safari tp");
+        assert_eq!(targets.android, Some(4 << 16 | 4 << 8 | 3));
+        assert_eq!(targets.chrome, Some(100 << 16));
+        assert_eq!(targets.safari, Some(15 << 16 | 4 << 8));
+        assert_eq!(targets.opera, None);
+    }
+
+    #[test]
+    fn clamping() {
+        assert_eq!(pack(&"300.1.2".parse().unwrap()), 255 << 16 | 1 << 8 | 2);
+    }
+
+    #[test]
+    fn sentinels_and_unsupported() {
+        assert_eq!(
+            convert("safari tp, op_mini all, kaios 2.5, ie_mob 11, node 18.0.0"),
+            LightningCssTargets::default()
+        );
+    }
+}
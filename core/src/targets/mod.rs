@@ -0,0 +1,44 @@
+use crate::{queries::Distrib, version::BrowserVersion};
+use alloc::collections::BTreeMap;
+
+pub(crate) mod babel;
+pub(crate) mod esbuild;
+pub(crate) mod lightningcss;
+
+/// Find the lowest version of each target,
+/// where the target name is mapped from browser name by `target_of`.
+///
+/// Joined versions like `15.2-15.3` are represented by their lower bounds,
+/// so the result only contains single versions and `TP`.
+/// `TP` is only kept when the target has no other versions,
+/// and `all` is ignored since it doesn't tell which version is the lowest.
+pub(crate) fn lowest_versions<F>(
+    distribs: &[Distrib],
+    target_of: F,
+) -> BTreeMap<&'static str, BrowserVersion>
+where
+    F: Fn(&str) -> Option<&'static str>,
+{
+    distribs
+        .iter()
+        .filter_map(|distrib| {
+            let target = target_of(distrib.name())?;
+            let version = match distrib.browser_version() {
+                BrowserVersion::Single(version) | BrowserVersion::Range(version, _) => {
+                    BrowserVersion::Single(version)
+                }
+                BrowserVersion::TechnologyPreview => BrowserVersion::TechnologyPreview,
+                BrowserVersion::All => return None,
+            };
+            Some((target, version))
+        })
+        .fold(BTreeMap::new(), |mut lowest, (target, version)| {
+            match lowest.get(target) {
+                Some(current) if *current <= version => {}
+                _ => {
+                    lowest.insert(target, version);
+                }
+            }
+            lowest
+        })
+}
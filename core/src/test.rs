@@ -0,0 +1,133 @@
+//! Comparing with fixtures recorded from JavaScript-based Browserslist.
+//!
+//! Fixtures are stored in `fixtures/browserslist.json` with versions of data they were recorded with,
+//! so tests can be run offline without Node.js.
+//!
+//! To refresh fixtures, install JavaScript-based Browserslist with data matching the vendored data,
+//! then run the recording script:
+//!
+//! ```sh
+//! pnpm install
+//! pnpm record-fixtures
+//! ```
+//!
+//! Queries of new cases can be passed to the script as arguments.
+
+use crate::{resolve, Error, Opts};
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use std::fs;
+
+const FIXTURES_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/browserslist.json");
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DataVersions {
+    caniuse: String,
+    electron_to_chromium: String,
+    node_releases: String,
+}
+
+impl DataVersions {
+    fn bundled() -> Self {
+        Self {
+            caniuse: env!("BROWSERSLIST_DATA_CANIUSE").into(),
+            electron_to_chromium: env!("BROWSERSLIST_DATA_ELECTRON_TO_CHROMIUM").into(),
+            node_releases: env!("BROWSERSLIST_DATA_NODE_RELEASES").into(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Fixtures {
+    data: DataVersions,
+    /// Unix timestamp when fixtures were recorded.
+    /// It's used as current time when resolving time-related queries.
+    recorded_at: i64,
+    cases: Vec<Case>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Case {
+    query: String,
+    #[serde(default)]
+    mobile_to_desktop: bool,
+    #[serde(default)]
+    ignore_unknown_versions: bool,
+    #[serde(flatten)]
+    outcome: Outcome,
+}
+
+/// Result of JavaScript-based Browserslist:
+/// either resolved browsers or message of the error it threw.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum Outcome {
+    Expected(Vec<String>),
+    Error(String),
+}
+
+impl Case {
+    fn matches(&self, query: &str, opts: &Opts) -> bool {
+        self.query == query
+            && self.mobile_to_desktop == opts.mobile_to_desktop
+            && self.ignore_unknown_versions == opts.ignore_unknown_versions
+    }
+}
+
+static FIXTURES: Lazy<Result<Fixtures, String>> = Lazy::new(load);
+
+fn load() -> Result<Fixtures, String> {
+    let content = fs::read(FIXTURES_PATH).map_err(|_| {
+        format!(
+            "fixtures not found at '{}'; run `pnpm record-fixtures` to record them",
+            FIXTURES_PATH
+        )
+    })?;
+    let fixtures: Fixtures = serde_json::from_slice(&content).map_err(|e| e.to_string())?;
+    if fixtures.data == DataVersions::bundled() {
+        Ok(fixtures)
+    } else {
+        Err(String::from(
+            "fixtures are recorded with different data; run `pnpm record-fixtures` to refresh them",
+        ))
+    }
+}
+
+pub fn run_compare(query: &str, opts: &Opts) {
+    let fixtures = FIXTURES.as_ref().unwrap_or_else(|e| panic!("{}", e));
+    let case =
+        fixtures
+            .cases
+            .iter()
+            .find(|case| case.matches(query, opts))
+            .unwrap_or_else(|| {
+                panic!(
+                "fixture of query '{}' not found; run `pnpm record-fixtures{}{} '{}'` to record it",
+                query,
+                if opts.mobile_to_desktop { " --mobile-to-desktop" } else { "" },
+                if opts.ignore_unknown_versions { " --ignore-unknown-versions" } else { "" },
+                query
+            )
+            });
+
+    let actual = resolve([query], opts.clone().now(fixtures.recorded_at))
+        .map(|distribs| distribs.iter().map(|d| d.to_string()).collect::<Vec<_>>());
+    match (&case.outcome, actual) {
+        (Outcome::Expected(expected), Ok(actual)) => assert_eq!(expected, &actual),
+        (Outcome::Expected(_), Err(error)) => {
+            panic!("query '{}' failed: {}", query, error)
+        }
+        (Outcome::Error(message), Ok(actual)) => panic!(
+            "query '{}' should fail with '{}', but resolved to {:?}",
+            query, message, actual
+        ),
+        (Outcome::Error(_), Err(_)) => {}
+    }
+}
+
+pub fn should_failed(query: &str, opts: &Opts) -> Error {
+    resolve([query], opts).unwrap_err()
+}
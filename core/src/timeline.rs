@@ -0,0 +1,187 @@
+use crate::{
+    coverage::{usage_lookup, Stats},
+    data::caniuse::{features::get_feature_stat, CANIUSE_BROWSERS},
+    error::Error,
+};
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+use serde::Serialize;
+
+/// Result of [`feature_timeline`].
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureTimeline {
+    first_support: BTreeMap<&'static str, FirstSupport>,
+    /// Release date and usage of each supporting version, sorted by release date.
+    #[serde(skip)]
+    adoption: Vec<(i64, f32)>,
+}
+
+impl FeatureTimeline {
+    /// Return the first version of each browser which supports the feature.
+    ///
+    /// Browsers which have never supported the feature aren't listed.
+    #[inline]
+    pub fn first_support(&self) -> &BTreeMap<&'static str, FirstSupport> {
+        &self.first_support
+    }
+
+    /// Return current usage (in percentage) of released browser versions which support the feature.
+    pub fn usage(&self) -> f32 {
+        self.adoption.iter().map(|(_, usage)| usage).sum()
+    }
+
+    /// Estimate the date (as Unix timestamp in seconds) since when
+    /// browsers supporting the feature have been used by at least `percentage` of users.
+    ///
+    /// Historical usage isn't available, so current usage of each version is used instead.
+    /// That is, this is the release date of the version
+    /// which makes usage of all supporting versions released so far reach `percentage`.
+    ///
+    /// It returns `None` if the current usage doesn't reach `percentage`.
+    pub fn supported_since(&self, percentage: f32) -> Option<i64> {
+        self.adoption
+            .iter()
+            .scan(0.0, |total, (date, usage)| {
+                *total += usage;
+                Some((*date, *total))
+            })
+            .find(|(_, total)| *total >= percentage)
+            .map(|(date, _)| date)
+    }
+}
+
+/// The first version of a browser which supports a feature.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FirstSupport {
+    version: &'static str,
+    release_date: Option<i64>,
+}
+
+impl FirstSupport {
+    /// Return browser version.
+    #[inline]
+    pub fn version(&self) -> &'static str {
+        self.version
+    }
+
+    /// Return release date as Unix timestamp (in seconds),
+    /// or `None` if the version hasn't been released.
+    #[inline]
+    pub fn release_date(&self) -> Option<i64> {
+        self.release_date
+    }
+}
+
+/// Build support timeline of a Can I Use feature,
+/// with usage of supporting versions taken from the given statistics.
+///
+/// Both full and partial support are counted, like `supports` query.
+///
+/// ```
+/// use browserslist::{feature_timeline, resolve, Opts, Stats};
+///
+/// let timeline = feature_timeline("es6", Stats::Global).unwrap();
+/// let chrome = timeline.first_support()["chrome"].version();
+/// let supported = resolve(["supports es6"], &Opts::new()).unwrap();
+/// assert!(supported
+///     .iter()
+///     .any(|distrib| distrib.name() == "chrome" && distrib.version() == chrome));
+///
+/// assert!(timeline.supported_since(1.0).is_some());
+/// assert_eq!(timeline.supported_since(101.0), None);
+/// ```
+pub fn feature_timeline(feature: &str, stats: Stats) -> Result<FeatureTimeline, Error> {
+    let stat = get_feature_stat(feature)
+        .ok_or_else(|| Error::UnknownBrowserFeature(feature.to_string()))?;
+    let usage_of = usage_lookup(stats)?;
+
+    let mut timeline = FeatureTimeline::default();
+    for (name, browser_stat) in CANIUSE_BROWSERS.iter() {
+        let supported = browser_stat.version_list.iter().filter(|detail| {
+            stat.iter()
+                .any(|(n, version, ..)| n == name && *version == detail.version)
+        });
+        for detail in supported {
+            let _ = timeline
+                .first_support
+                .entry(*name)
+                .or_insert_with(|| FirstSupport {
+                    version: detail.version,
+                    release_date: detail.release_date,
+                });
+            if let Some(release_date) = detail.release_date {
+                let usage = usage_of(name, detail.version).unwrap_or_default();
+                timeline.adoption.push((release_date, usage));
+            }
+        }
+    }
+    timeline.adoption.sort_by_key(|(date, _)| *date);
+
+    Ok(timeline)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coverage::coverage, opts::Opts, resolve};
+    use alloc::string::String;
+
+    #[test]
+    fn first_support() {
+        let timeline = feature_timeline("es6", Stats::Global).unwrap();
+        let chrome = &timeline.first_support()["chrome"];
+        let release_date = chrome.release_date().unwrap();
+        let supported = resolve(["supports es6"], &Opts::new()).unwrap();
+        let supported_chrome = supported
+            .iter()
+            .filter(|distrib| distrib.name() == "chrome")
+            .collect::<Vec<_>>();
+        assert!(supported_chrome
+            .iter()
+            .any(|distrib| distrib.version() == chrome.version()));
+        assert!(supported_chrome
+            .iter()
+            .filter_map(|distrib| distrib.release_date())
+            .all(|date| date >= release_date));
+        assert!(!timeline.first_support().contains_key("ie"));
+        assert!(!timeline.first_support().contains_key("op_mini"));
+    }
+
+    #[test]
+    fn usage_matches_coverage() {
+        let timeline = feature_timeline("es6", Stats::Global).unwrap();
+        let distribs = resolve(["supports es6"], &Opts::new())
+            .unwrap()
+            .into_iter()
+            .filter(|distrib| distrib.release_date().is_some())
+            .collect::<Vec<_>>();
+        let coverage = coverage(&distribs, Stats::Global).unwrap();
+        assert!((timeline.usage() - coverage.total()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn supported_since() {
+        let timeline = feature_timeline("es6", Stats::Region("US")).unwrap();
+        assert_eq!(
+            timeline.supported_since(0.0),
+            timeline.adoption.first().map(|(date, _)| *date)
+        );
+        let half = timeline.supported_since(timeline.usage() / 2.0).unwrap();
+        let full = timeline.supported_since(timeline.usage()).unwrap();
+        assert!(half <= full);
+        assert_eq!(timeline.supported_since(timeline.usage() + 1.0), None);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            feature_timeline("xxxyyyzzz", Stats::Global),
+            Err(Error::UnknownBrowserFeature(String::from("xxxyyyzzz")))
+        );
+        assert_eq!(
+            feature_timeline("es6", Stats::Region("XX")),
+            Err(Error::UnknownRegion(String::from("XX")))
+        );
+    }
+}
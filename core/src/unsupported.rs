@@ -0,0 +1,160 @@
+use crate::{
+    coverage::{coverage, usage_lookup, Stats},
+    data::caniuse::features::{get_feature_stat, FEATURE_NAMES},
+    error::Error,
+    queries::Distrib,
+};
+use alloc::{string::ToString, vec::Vec};
+use serde::Serialize;
+
+/// Feature which isn't supported by some browsers, returned by [`unsupported_features`].
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct UnsupportedFeature {
+    feature: &'static str,
+    browsers: Vec<Distrib>,
+    usage: f32,
+}
+
+impl UnsupportedFeature {
+    /// Return name of Can I Use feature.
+    #[inline]
+    pub fn feature(&self) -> &'static str {
+        self.feature
+    }
+
+    /// Return browsers which don't support this feature.
+    #[inline]
+    pub fn browsers(&self) -> &[Distrib] {
+        &self.browsers
+    }
+
+    /// Return share of users (in percentage) using browsers which don't support this feature.
+    #[inline]
+    pub fn usage(&self) -> f32 {
+        self.usage
+    }
+}
+
+/// List Can I Use features which aren't supported by at least one of the given browsers,
+/// sorted by share of affected users in descending order.
+///
+/// Only the given features are checked, or all Can I Use features if `features` is `None`.
+/// Both full and partial support are counted as supported, like `supports` query.
+/// Can I Use doesn't have data of Node.js, so Node.js versions are ignored.
+///
+/// ```
+/// use browserslist::{resolve, unsupported_features, Opts, Stats};
+///
+/// let distribs = resolve(["ie 11, chrome 100"], &Opts::new()).unwrap();
+/// let report = unsupported_features(&distribs, Some(&["es5", "es6"]), Stats::Global).unwrap();
+///
+/// assert_eq!(report.len(), 1);
+/// assert_eq!(report[0].feature(), "es6");
+/// assert_eq!(report[0].browsers()[0].to_string(), "ie 11");
+/// ```
+pub fn unsupported_features(
+    distribs: &[Distrib],
+    features: Option<&[&str]>,
+    stats: Stats,
+) -> Result<Vec<UnsupportedFeature>, Error> {
+    // fail early even if all features are supported
+    let _ = usage_lookup(stats)?;
+
+    let features = match features {
+        Some(features) => features
+            .iter()
+            .map(|feature| {
+                FEATURE_NAMES
+                    .iter()
+                    .find(|name| *name == feature)
+                    .copied()
+                    .ok_or_else(|| Error::UnknownBrowserFeature(feature.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        None => FEATURE_NAMES.to_vec(),
+    };
+
+    let mut report = features
+        .into_iter()
+        .filter_map(|feature| {
+            let stat = get_feature_stat(feature)?;
+            let browsers = distribs
+                .iter()
+                .filter(|distrib| {
+                    !distrib.is_node()
+                        && !stat.iter().any(|(name, version, ..)| {
+                            *name == distrib.name() && *version == distrib.version()
+                        })
+                })
+                .copied()
+                .collect::<Vec<_>>();
+            if browsers.is_empty() {
+                None
+            } else {
+                Some((feature, browsers))
+            }
+        })
+        .map(|(feature, browsers)| {
+            Ok(UnsupportedFeature {
+                feature,
+                usage: coverage(&browsers, stats)?.total(),
+                browsers,
+            })
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    report.sort_by(|a, b| b.usage.total_cmp(&a.usage));
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{opts::Opts, resolve};
+    use alloc::string::String;
+
+    #[test]
+    fn all_features() {
+        let distribs = resolve(["ie 11, chrome 100, node 10.0.0"], &Opts::new()).unwrap();
+        let report = unsupported_features(&distribs, None, Stats::Global).unwrap();
+        assert!(report.iter().any(|item| item.feature() == "es6"));
+        assert!(!report.iter().any(|item| item.feature() == "es5"));
+        assert!(report.iter().all(|item| !item.browsers().is_empty()
+            && item.browsers().iter().all(|distrib| !distrib.is_node())));
+        assert!(report
+            .windows(2)
+            .all(|pair| pair[0].usage() >= pair[1].usage()));
+    }
+
+    #[test]
+    fn usage() {
+        let distribs = resolve(["ie 11, op_mini all, chrome 100"], &Opts::new()).unwrap();
+        let report = unsupported_features(&distribs, Some(&["es6"]), Stats::Region("US")).unwrap();
+        let expected = coverage(report[0].browsers(), Stats::Region("US"))
+            .unwrap()
+            .total();
+        assert_eq!(report[0].usage(), expected);
+    }
+
+    #[test]
+    fn all_supported() {
+        let distribs = resolve(["chrome 100"], &Opts::new()).unwrap();
+        assert!(
+            unsupported_features(&distribs, Some(&["es5", "es6"]), Stats::Global)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            unsupported_features(&[], Some(&["xxxyyyzzz"]), Stats::Global),
+            Err(Error::UnknownBrowserFeature(String::from("xxxyyyzzz")))
+        );
+        assert_eq!(
+            unsupported_features(&[], None, Stats::Region("XX")),
+            Err(Error::UnknownRegion(String::from("XX")))
+        );
+    }
+}
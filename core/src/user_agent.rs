@@ -0,0 +1,742 @@
+use crate::{
+    data::caniuse::{get_browser_stat, normalize_version},
+    error::Error,
+    queries::Distrib,
+    version::{BrowserVersion, VersionNumber},
+};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{cmp::Ordering, iter::Peekable, str::Chars};
+
+/// Options for matching User-Agent with [`matches_user_agent`].
+#[derive(Clone, Debug)]
+pub struct UserAgentOpts {
+    pub(crate) allow_higher_versions: bool,
+    pub(crate) ignore_patch: bool,
+    pub(crate) ignore_minor: bool,
+}
+
+impl Default for UserAgentOpts {
+    fn default() -> Self {
+        Self {
+            allow_higher_versions: false,
+            ignore_patch: true,
+            ignore_minor: false,
+        }
+    }
+}
+
+impl UserAgentOpts {
+    /// Create new options with default values.
+    ///
+    /// By default, higher versions aren't allowed, patch versions are ignored
+    /// and minor versions are compared.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Match browsers whose versions are higher than the lowest resolved version of the same browser,
+    /// even if those versions aren't resolved or aren't known by Can I Use yet.
+    pub fn allow_higher_versions(&mut self, flag: bool) -> &mut Self {
+        self.allow_higher_versions = flag;
+        self
+    }
+
+    /// Ignore patch version when comparing, so `15.4.1` is considered as `15.4`.
+    pub fn ignore_patch(&mut self, flag: bool) -> &mut Self {
+        self.ignore_patch = flag;
+        self
+    }
+
+    /// Ignore minor and patch versions when comparing, so `15.4.1` is considered as `15`.
+    pub fn ignore_minor(&mut self, flag: bool) -> &mut Self {
+        self.ignore_minor = flag;
+        self
+    }
+
+    pub(crate) fn depth(&self) -> u8 {
+        if self.ignore_minor {
+            1
+        } else if self.ignore_patch {
+            2
+        } else {
+            3
+        }
+    }
+}
+
+/// Browser detected from User-Agent, with its version as it's written there.
+struct Detected {
+    name: &'static str,
+    version: VersionNumber,
+}
+
+/// Parse User-Agent string, then return browser with its version as it's in Can I Use.
+///
+/// Browsers on iOS are all treated as `ios_saf`, since they're all built on WebKit,
+/// while Android WebView is treated as `android`.
+///
+/// Can I Use only has the latest version of some mobile browsers like `and_chr`,
+/// so versions of mobile browsers are also looked up from desktop versions,
+/// like what [`Opts::mobile_to_desktop`](crate::Opts::mobile_to_desktop) does.
+///
+/// ```
+/// use browserslist::parse_user_agent;
+///
+/// let distrib = parse_user_agent(
+///     "Mozilla/5.0 (iPhone; CPU iPhone OS 15_2 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/15.2 Mobile/15E148 Safari/604.1",
+/// )
+/// .unwrap();
+/// assert_eq!(distrib.name(), "ios_saf");
+/// assert_eq!(distrib.version(), "15.2-15.3");
+/// ```
+pub fn parse_user_agent(ua: &str) -> Result<Distrib, Error> {
+    let detected = detect(ua).ok_or_else(|| Error::UnknownUserAgent(ua.to_string()))?;
+    to_distrib(&detected)
+}
+
+/// Check if browser of the given User-Agent is one of the resolved browsers.
+///
+/// Unrecognized User-Agent never matches.
+/// Since Can I Use only has the latest version of some mobile browsers like `and_chr`,
+/// resolve queries with [`Opts::mobile_to_desktop`](crate::Opts::mobile_to_desktop)
+/// to match their older versions.
+///
+/// ```
+/// use browserslist::{matches_user_agent, resolve, Opts, UserAgentOpts};
+///
+/// let distribs = resolve(["firefox 120"], &Opts::new()).unwrap();
+/// let ua = "Mozilla/5.0 (X11; Linux x86_64; rv:120.0) Gecko/20100101 Firefox/120.0";
+/// assert!(matches_user_agent(ua, &distribs, &UserAgentOpts::new()));
+///
+/// let ua = "Mozilla/5.0 (X11; Linux x86_64; rv:121.0) Gecko/20100101 Firefox/121.0";
+/// assert!(!matches_user_agent(ua, &distribs, &UserAgentOpts::new()));
+/// assert!(matches_user_agent(
+///     ua,
+///     &distribs,
+///     UserAgentOpts::new().allow_higher_versions(true)
+/// ));
+/// ```
+pub fn matches_user_agent(ua: &str, distribs: &[Distrib], opts: &UserAgentOpts) -> bool {
+    detect(ua).is_some_and(|detected| matches_detected(&detected, distribs, opts))
+}
+
+/// User-Agent Client Hints sent by browser, with User-Agent string as fallback.
+///
+/// Each value is the raw value of the corresponding HTTP header.
+#[derive(Clone, Debug, Default)]
+pub struct ClientHints {
+    sec_ch_ua: Option<String>,
+    sec_ch_ua_full_version_list: Option<String>,
+    sec_ch_ua_mobile: Option<String>,
+    user_agent: Option<String>,
+}
+
+impl ClientHints {
+    /// Create client hints without any headers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Value of `Sec-CH-UA` header, like `"Chromium";v="119", "Google Chrome";v="119"`.
+    pub fn sec_ch_ua<S: AsRef<str>>(&mut self, value: S) -> &mut Self {
+        self.sec_ch_ua = Some(value.as_ref().to_string());
+        self
+    }
+
+    /// Value of `Sec-CH-UA-Full-Version-List` header,
+    /// like `"Chromium";v="119.0.6045.159", "Google Chrome";v="119.0.6045.159"`.
+    ///
+    /// If it's present, versions are taken from it instead of `Sec-CH-UA`.
+    pub fn sec_ch_ua_full_version_list<S: AsRef<str>>(&mut self, value: S) -> &mut Self {
+        self.sec_ch_ua_full_version_list = Some(value.as_ref().to_string());
+        self
+    }
+
+    /// Value of `Sec-CH-UA-Mobile` header, which is `?1` on mobile devices.
+    pub fn sec_ch_ua_mobile<S: AsRef<str>>(&mut self, value: S) -> &mut Self {
+        self.sec_ch_ua_mobile = Some(value.as_ref().to_string());
+        self
+    }
+
+    /// Value of `User-Agent` header, which is used when client hints don't have known brands.
+    pub fn user_agent<S: AsRef<str>>(&mut self, value: S) -> &mut Self {
+        self.user_agent = Some(value.as_ref().to_string());
+        self
+    }
+
+    fn detect(&self) -> Option<Detected> {
+        let is_mobile = self.sec_ch_ua_mobile.as_deref().map(str::trim) == Some("?1");
+
+        let brands = self
+            .sec_ch_ua_full_version_list
+            .as_deref()
+            .or(self.sec_ch_ua.as_deref())
+            .map(parse_brands)
+            .unwrap_or_default();
+        // Chromium-based browsers also send `Chromium` brand, so it's checked last
+        let detected = [
+            ("Microsoft Edge", "edge", "and_chr"),
+            ("Opera", "opera", "op_mob"),
+            ("Samsung Internet", "samsung", "samsung"),
+            ("Google Chrome", "chrome", "and_chr"),
+            ("Chromium", "chrome", "and_chr"),
+        ]
+        .iter()
+        .find_map(|(brand, desktop, mobile)| {
+            let (_, version) = brands.iter().find(|(name, _)| name == brand)?;
+            Some(Detected {
+                name: if is_mobile { mobile } else { desktop },
+                version: parse_version(version)?,
+            })
+        });
+
+        detected.or_else(|| self.user_agent.as_deref().and_then(detect))
+    }
+}
+
+/// Parse client hints, then return browser with its version as it's in Can I Use.
+///
+/// Brands of Google Chrome, Microsoft Edge, Opera and Samsung Internet are recognized,
+/// and Microsoft Edge on mobile devices is treated as `and_chr` like when parsing User-Agent.
+/// If there's no known brand, User-Agent string will be parsed instead.
+///
+/// ```
+/// use browserslist::{parse_client_hints, ClientHints};
+///
+/// let distrib = parse_client_hints(
+///     ClientHints::new()
+///         .sec_ch_ua(r#""Chromium";v="119", "Not?A_Brand";v="24", "Google Chrome";v="119""#)
+///         .sec_ch_ua_mobile("?0"),
+/// )
+/// .unwrap();
+/// assert_eq!(distrib.name(), "chrome");
+/// assert_eq!(distrib.version(), "119");
+/// ```
+pub fn parse_client_hints(hints: &ClientHints) -> Result<Distrib, Error> {
+    let detected = hints.detect().ok_or_else(|| {
+        Error::UnknownUserAgent(
+            hints
+                .sec_ch_ua
+                .clone()
+                .or_else(|| hints.user_agent.clone())
+                .unwrap_or_default(),
+        )
+    })?;
+    to_distrib(&detected)
+}
+
+/// Check if browser of the given client hints is one of the resolved browsers.
+///
+/// If there's no known brand in client hints, User-Agent string will be checked instead.
+/// Unrecognized browser never matches.
+///
+/// ```
+/// use browserslist::{matches_client_hints, resolve, ClientHints, Opts, UserAgentOpts};
+///
+/// let distribs = resolve(["edge 119"], &Opts::new()).unwrap();
+/// let mut hints = ClientHints::new();
+/// hints
+///     .sec_ch_ua(r#""Microsoft Edge";v="119", "Chromium";v="119", "Not?A_Brand";v="24""#)
+///     .sec_ch_ua_mobile("?0");
+///
+/// assert!(matches_client_hints(&hints, &distribs, &UserAgentOpts::new()));
+/// ```
+pub fn matches_client_hints(
+    hints: &ClientHints,
+    distribs: &[Distrib],
+    opts: &UserAgentOpts,
+) -> bool {
+    hints
+        .detect()
+        .is_some_and(|detected| matches_detected(&detected, distribs, opts))
+}
+
+fn to_distrib(detected: &Detected) -> Result<Distrib, Error> {
+    let (name, stat) = get_browser_stat(detected.name, false)
+        .ok_or_else(|| Error::BrowserNotFound(detected.name.to_string()))?;
+    let desktop_stat = get_browser_stat(name, true).map(|(_, stat)| stat);
+
+    // Can I Use may not have older versions of mobile browsers,
+    // so they're also looked up from desktop versions like `mobile_to_desktop` option.
+    // Browsers like `and_chr` only have the latest version which any version is normalized to,
+    // so desktop versions are tried first for them.
+    let stats = if stat.version_list.len() == 1 {
+        [desktop_stat, Some(stat)]
+    } else {
+        [Some(stat), desktop_stat]
+    };
+
+    let version = &detected.version;
+    stats
+        .into_iter()
+        .flatten()
+        .find_map(|stat| {
+            // try from the most precise version, since Can I Use versions are like `119` or `15.2-15.3`
+            (1..=version.components())
+                .rev()
+                .find_map(|count| normalize_version(stat, &format_components(version, count)))
+        })
+        .map(|version| Distrib::new(name, version))
+        .ok_or_else(|| Error::UnknownBrowserVersion(name.to_string(), version.to_string()))
+}
+
+fn matches_detected(detected: &Detected, distribs: &[Distrib], opts: &UserAgentOpts) -> bool {
+    let depth = opts.depth();
+
+    distribs
+        .iter()
+        .filter(|distrib| distrib.name() == detected.name)
+        .any(|distrib| match distrib.browser_version() {
+            BrowserVersion::All => true,
+            BrowserVersion::TechnologyPreview => false,
+            BrowserVersion::Single(version) => match compare(&detected.version, &version, depth) {
+                Ordering::Equal => true,
+                Ordering::Greater => opts.allow_higher_versions,
+                Ordering::Less => false,
+            },
+            BrowserVersion::Range(lower, upper) => {
+                compare(&detected.version, &lower, depth).is_ge()
+                    && (opts.allow_higher_versions
+                        || compare(&detected.version, &upper, depth).is_le())
+            }
+        })
+}
+
+/// Parse brand list in the format of structured header, like `"Chromium";v="119"`.
+///
+/// Brand names are quoted strings which may contain `,` or `;`,
+/// since browsers add fake brands with such characters on purpose.
+fn parse_brands(value: &str) -> Vec<(String, String)> {
+    let mut brands = vec![];
+    let mut chars = value.chars().peekable();
+    loop {
+        while chars.next_if(|c| *c == ' ' || *c == ',').is_some() {}
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let brand = parse_item(&mut chars);
+        let mut version = None;
+        loop {
+            while chars.next_if_eq(&' ').is_some() {}
+            if chars.next_if_eq(&';').is_none() {
+                break;
+            }
+            while chars.next_if_eq(&' ').is_some() {}
+            let mut key = String::new();
+            while let Some(c) = chars.next_if(|c| !matches!(c, '=' | ',' | ';')) {
+                key.push(c);
+            }
+            // parameter without value, like `;x`, or a malformed one which ends at next member
+            if chars.next_if_eq(&'=').is_none() {
+                continue;
+            }
+            let value = parse_item(&mut chars);
+            if key.trim() == "v" {
+                version = Some(value);
+            }
+        }
+        // skip malformed rest of this member
+        while chars.next_if(|c| *c != ',').is_some() {}
+
+        if let Some(version) = version {
+            brands.push((brand, version));
+        }
+    }
+    brands
+}
+
+/// Parse quoted string or bare token.
+fn parse_item(chars: &mut Peekable<Chars>) -> String {
+    if chars.next_if_eq(&'"').is_some() {
+        let mut item = String::new();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => break,
+                '\\' => item.extend(chars.next()),
+                c => item.push(c),
+            }
+        }
+        item
+    } else {
+        let mut item = String::new();
+        while let Some(c) = chars.next_if(|c| !matches!(c, ',' | ';')) {
+            item.push(c);
+        }
+        item.trim().to_string()
+    }
+}
+
+/// Compare detected version with resolved version,
+/// only considering components which are written in resolved version and aren't ignored.
+fn compare(detected: &VersionNumber, resolved: &VersionNumber, depth: u8) -> Ordering {
+    let depth = depth.min(resolved.components());
+    let components = |version: &VersionNumber| {
+        [version.major(), version.minor(), version.patch()]
+            .into_iter()
+            .take(depth as usize)
+            .collect::<Vec<_>>()
+    };
+    components(detected).cmp(&components(resolved))
+}
+
+fn format_components(version: &VersionNumber, count: u8) -> String {
+    [version.major(), version.minor(), version.patch()]
+        .iter()
+        .take(count as usize)
+        .map(|component| component.to_string())
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn detect(ua: &str) -> Option<Detected> {
+    let is_android = ua.contains("Android");
+
+    let (name, version) = if ua.contains("Opera Mini/") {
+        ("op_mini", "0")
+    } else if ua.contains("iPhone") || ua.contains("iPad") || ua.contains("iPod") {
+        ("ios_saf", version_after(ua, " OS ")?)
+    } else if let Some(version) = version_after(ua, "KAIOS/") {
+        ("kaios", version)
+    } else if ua.contains("BB10") || ua.contains("BlackBerry") {
+        ("bb", version_after(ua, "Version/")?)
+    } else if let Some(version) = version_after(ua, "IEMobile/") {
+        ("ie_mob", version)
+    } else if let Some(version) = version_after(ua, "MSIE ") {
+        ("ie", version)
+    } else if ua.contains("Trident/") {
+        ("ie", version_after(ua, "rv:")?)
+    } else if let Some(version) = version_after(ua, "Edge/") {
+        ("edge", version)
+    } else if let Some(version) = version_after(ua, "Edg/") {
+        ("edge", version)
+    } else if let Some(version) = version_after(ua, "SamsungBrowser/") {
+        ("samsung", version)
+    } else if let Some(version) = version_after(ua, "UCBrowser/") {
+        ("and_uc", version)
+    } else if let Some(version) = version_after(ua, "MQQBrowser/") {
+        ("and_qq", version)
+    } else if let Some(version) =
+        version_after(ua, "baidubrowser/").or_else(|| version_after(ua, "bdbrowser/"))
+    {
+        ("baidu", version)
+    } else if let Some(version) = version_after(ua, "OPR/") {
+        (if is_android { "op_mob" } else { "opera" }, version)
+    } else if ua.starts_with("Opera/") {
+        (
+            if ua.contains("Mobi") {
+                "op_mob"
+            } else {
+                "opera"
+            },
+            version_after(ua, "Version/").or_else(|| version_after(ua, "Opera/"))?,
+        )
+    } else if let Some(version) = version_after(ua, "Firefox/") {
+        (if is_android { "and_ff" } else { "firefox" }, version)
+    } else if is_android && (ua.contains("; wv)") || ua.contains("Version/")) {
+        // old Android browser uses version of Android,
+        // while Android WebView since Android 5 uses version of Chrome
+        let os_version = version_after(ua, "Android ")?;
+        match version_after(ua, "Chrome/") {
+            Some(version) if parse_version(os_version)?.major() >= 5 => ("android", version),
+            _ => ("android", os_version),
+        }
+    } else if let Some(version) =
+        version_after(ua, "Chrome/").or_else(|| version_after(ua, "Chromium/"))
+    {
+        (if is_android { "and_chr" } else { "chrome" }, version)
+    } else if ua.contains("Safari/") {
+        ("safari", version_after(ua, "Version/")?)
+    } else {
+        return None;
+    };
+
+    Some(Detected {
+        name,
+        version: parse_version(version)?,
+    })
+}
+
+/// Return version after the given token, which consists of digits with `.` or `_` as separator.
+fn version_after<'a>(ua: &'a str, token: &str) -> Option<&'a str> {
+    let start = ua.find(token)? + token.len();
+    let rest = &ua[start..];
+    let end = rest
+        .find(|c: char| !c.is_ascii_digit() && c != '.' && c != '_')
+        .unwrap_or(rest.len());
+    let version = &rest[..end];
+    if version.starts_with(|c: char| c.is_ascii_digit()) {
+        Some(version)
+    } else {
+        None
+    }
+}
+
+/// Parse version like `119.0.6045.159` or `15_2`, dropping components after patch version.
+fn parse_version(version: &str) -> Option<VersionNumber> {
+    version
+        .split(['.', '_'])
+        .take_while(|component| !component.is_empty())
+        .take(3)
+        .collect::<Vec<_>>()
+        .join(".")
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{opts::Opts, resolve};
+    use test_case::test_case;
+
+    const CHROME_119: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/119.0.6045.159 Safari/537.36";
+
+    #[test_case(CHROME_119, "chrome", "119"; "chrome")]
+    #[test_case(
+        "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/119.0.0.0 Mobile Safari/537.36",
+        "and_chr", "119"; "chrome for android"
+    )]
+    #[test_case(
+        "Mozilla/5.0 (Linux; Android 10; K; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/119.0.0.0 Mobile Safari/537.36",
+        "android", "119"; "android webview"
+    )]
+    #[test_case(
+        "Mozilla/5.0 (Linux; U; Android 4.4.4; en-us; Nexus 5 Build/KTU84P) AppleWebKit/534.30 (KHTML, like Gecko) Version/4.0 Mobile Safari/534.30",
+        "android", "4.4.3-4.4.4"; "android browser"
+    )]
+    #[test_case(
+        "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/15.4 Safari/605.1.15",
+        "safari", "15.4"; "safari"
+    )]
+    #[test_case(
+        "Mozilla/5.0 (iPad; CPU OS 17_1_2 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) CriOS/119.0.6045.169 Mobile/15E148 Safari/604.1",
+        "ios_saf", "17.1"; "chrome on ios"
+    )]
+    #[test_case(
+        "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:121.0) Gecko/20100101 Firefox/121.0",
+        "firefox", "121"; "firefox"
+    )]
+    #[test_case(
+        "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/119.0.0.0 Safari/537.36 Edg/119.0.2151.72",
+        "edge", "119"; "edge"
+    )]
+    #[test_case(
+        "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/114.0.0.0 Safari/537.36 OPR/100.0.0.0",
+        "opera", "100"; "opera"
+    )]
+    #[test_case(
+        "Mozilla/5.0 (Windows NT 6.1; WOW64; Trident/7.0; rv:11.0) like Gecko",
+        "ie", "11"; "ie 11"
+    )]
+    #[test_case(
+        "Mozilla/5.0 (compatible; MSIE 10.0; Windows NT 6.1; Trident/6.0)",
+        "ie", "10"; "ie 10"
+    )]
+    #[test_case(
+        "Opera/9.80 (Android; Opera Mini/36.2.2254/119.132; U; id) Presto/2.12.423 Version/12.16",
+        "op_mini", "all"; "opera mini"
+    )]
+    #[test_case(
+        "Mozilla/5.0 (Linux; Android 13; SM-S901B) AppleWebKit/537.36 (KHTML, like Gecko) SamsungBrowser/23.0 Chrome/115.0.0.0 Mobile Safari/537.36",
+        "samsung", "23"; "samsung internet"
+    )]
+    fn parse(ua: &str, name: &str, version: &str) {
+        let distrib = parse_user_agent(ua).unwrap();
+        assert_eq!(distrib.name(), name);
+        assert_eq!(distrib.version(), version);
+    }
+
+    #[test]
+    fn parse_failures() {
+        assert_eq!(
+            parse_user_agent("curl/8.4.0"),
+            Err(Error::UnknownUserAgent("curl/8.4.0".into()))
+        );
+        assert_eq!(
+            parse_user_agent(
+                "Mozilla/5.0 (X11; Linux x86_64; rv:999.0) Gecko/20100101 Firefox/999.0"
+            ),
+            Err(Error::UnknownBrowserVersion(
+                "firefox".into(),
+                "999.0".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn matching() {
+        let distribs =
+            resolve(["chrome 119, ios_saf 15.2-15.3, op_mini all"], &Opts::new()).unwrap();
+        let opts = UserAgentOpts::new();
+
+        assert!(matches_user_agent(CHROME_119, &distribs, &opts));
+        assert!(!matches_user_agent("curl/8.4.0", &distribs, &opts));
+        assert!(matches_user_agent(
+            "Opera/9.80 (J2ME/MIDP; Opera Mini/9.80 (S60; SymbOS; Opera Mobi/23.348; U; en) Presto/2.5.25 Version/10.54",
+            &distribs,
+            &opts
+        ));
+
+        let ios = |version: &str| {
+            alloc::format!("Mozilla/5.0 (iPhone; CPU iPhone OS {version} like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/15.2 Mobile/15E148 Safari/604.1")
+        };
+        assert!(matches_user_agent(&ios("15_2"), &distribs, &opts));
+        assert!(matches_user_agent(&ios("15_3_1"), &distribs, &opts));
+        assert!(!matches_user_agent(&ios("15_4"), &distribs, &opts));
+        assert!(!matches_user_agent(&ios("15_1"), &distribs, &opts));
+        assert!(matches_user_agent(
+            &ios("15_4"),
+            &distribs,
+            UserAgentOpts::new().allow_higher_versions(true)
+        ));
+        assert!(matches_user_agent(
+            &ios("15_9"),
+            &distribs,
+            UserAgentOpts::new().ignore_minor(true)
+        ));
+    }
+
+    #[test]
+    fn mobile_to_desktop() {
+        let ua = "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/110.0.0.0 Mobile Safari/537.36";
+        let distrib = parse_user_agent(ua).unwrap();
+        assert_eq!(distrib.name(), "and_chr");
+        assert_eq!(distrib.version(), "110");
+
+        let opts = UserAgentOpts::new();
+        let distribs = resolve(["and_chr >= 100"], Opts::new().mobile_to_desktop(true)).unwrap();
+        assert!(matches_user_agent(ua, &distribs, &opts));
+        let distribs = resolve(["last 1 and_chr version"], &Opts::new()).unwrap();
+        assert!(!matches_user_agent(ua, &distribs, &opts));
+
+        let webview = "Mozilla/5.0 (Linux; Android 10; K; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/110.0.0.0 Mobile Safari/537.36";
+        assert_eq!(
+            parse_user_agent(webview).unwrap().to_string(),
+            "android 110"
+        );
+    }
+
+    #[test]
+    fn matching_patch() {
+        let distribs = resolve(["android 4.4.3-4.4.4"], &Opts::new()).unwrap();
+        let ua = |version: &str| {
+            alloc::format!("Mozilla/5.0 (Linux; U; Android {version}; en-us) AppleWebKit/534.30 (KHTML, like Gecko) Version/4.0 Mobile Safari/534.30")
+        };
+
+        assert!(matches_user_agent(
+            &ua("4.4.2"),
+            &distribs,
+            &UserAgentOpts::new()
+        ));
+        assert!(!matches_user_agent(
+            &ua("4.4.2"),
+            &distribs,
+            UserAgentOpts::new().ignore_patch(false)
+        ));
+        assert!(matches_user_agent(
+            &ua("4.4.4"),
+            &distribs,
+            UserAgentOpts::new().ignore_patch(false)
+        ));
+    }
+
+    #[test_case(
+        r#""Chromium";v="119", "Not?A_Brand";v="24", "Google Chrome";v="119""#, "?0",
+        "chrome", "119"; "chrome"
+    )]
+    #[test_case(
+        r#""Google Chrome";v="119", "Chromium";v="119", "Not?A_Brand";v="24""#, "?1",
+        "and_chr", "119"; "chrome for android"
+    )]
+    #[test_case(
+        r#""Microsoft Edge";v="119", "Chromium";v="119", "Not?A_Brand";v="24""#, "?0",
+        "edge", "119"; "edge"
+    )]
+    #[test_case(
+        r#""Opera";v="105", "Chromium";v="119", "Not?A_Brand";v="24""#, "?0",
+        "opera", "105"; "opera"
+    )]
+    #[test_case(
+        r#""Samsung Internet";v="23.0", "Chromium";v="115", "Not/A)Brand";v="99""#, "?1",
+        "samsung", "23"; "samsung internet"
+    )]
+    #[test_case(
+        r#""Not;A=Brand";v="8", "Chromium";v="118""#, "?0",
+        "chrome", "118"; "grease brand with separators"
+    )]
+    fn parse_hints(sec_ch_ua: &str, mobile: &str, name: &str, version: &str) {
+        let distrib = parse_client_hints(
+            ClientHints::new()
+                .sec_ch_ua(sec_ch_ua)
+                .sec_ch_ua_mobile(mobile),
+        )
+        .unwrap();
+        assert_eq!(distrib.name(), name);
+        assert_eq!(distrib.version(), version);
+    }
+
+    #[test]
+    fn brands() {
+        assert_eq!(
+            parse_brands(
+                r#""Not\"A;Brand";v="99" , "Chromium" ; v="119.0.1", Bare;v=1, "NoVersion""#
+            ),
+            [
+                ("Not\"A;Brand".into(), "99".into()),
+                ("Chromium".into(), "119.0.1".into()),
+                ("Bare".into(), "1".into()),
+            ]
+        );
+        assert_eq!(
+            parse_brands(r#""Broken";x, "Chromium";v="119""#),
+            [("Chromium".into(), "119".into())]
+        );
+        assert_eq!(
+            parse_brands(r#""Flag";x;v="1", "Chromium";v="119""#),
+            [
+                ("Flag".into(), "1".into()),
+                ("Chromium".into(), "119".into())
+            ]
+        );
+        assert!(parse_brands("").is_empty());
+        assert!(parse_brands(r#""unterminated"#).is_empty());
+    }
+
+    #[test]
+    fn matching_hints() {
+        let distribs = resolve(["chrome 119, firefox 120"], &Opts::new()).unwrap();
+        let opts = UserAgentOpts::new();
+
+        let mut hints = ClientHints::new();
+        hints
+            .sec_ch_ua(r#""Google Chrome";v="118", "Chromium";v="118""#)
+            .sec_ch_ua_full_version_list(
+                r#""Google Chrome";v="119.0.6045.159", "Chromium";v="119.0.6045.159""#,
+            )
+            .user_agent("Mozilla/5.0 (X11; Linux x86_64; rv:120.0) Gecko/20100101 Firefox/999.0");
+        assert!(matches_client_hints(&hints, &distribs, &opts));
+        assert!(!matches_client_hints(
+            hints.sec_ch_ua_mobile("?1"),
+            &distribs,
+            &opts
+        ));
+
+        // fall back to User-Agent when hints are absent
+        let mut hints = ClientHints::new();
+        hints.user_agent("Mozilla/5.0 (X11; Linux x86_64; rv:120.0) Gecko/20100101 Firefox/120.0");
+        assert!(matches_client_hints(&hints, &distribs, &opts));
+        hints.sec_ch_ua(r#""Not?A_Brand";v="24""#);
+        assert!(matches_client_hints(&hints, &distribs, &opts));
+
+        assert!(!matches_client_hints(&ClientHints::new(), &distribs, &opts));
+        assert_eq!(
+            parse_client_hints(&ClientHints::new()),
+            Err(Error::UnknownUserAgent(String::new()))
+        );
+    }
+}
@@ -0,0 +1,634 @@
+use crate::{
+    queries::Distrib,
+    user_agent::UserAgentOpts,
+    version::{BrowserVersion, VersionNumber},
+};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt;
+
+/// Component which accepts any number, used as upper bound.
+const ANY: u32 = u32::MAX;
+
+/// Regex which marks the end of version when there's nothing specific after it.
+const END: &str = r"(?:[^\d._]|$)";
+
+/// Regular expression which matches User-Agent strings of resolved browsers,
+/// like what `browserslist-useragent-regexp` generates.
+///
+/// It matches the same User-Agent strings as [`matches_user_agent`](crate::matches_user_agent)
+/// with the same options, so versions which aren't resolved,
+/// such as Chrome for Android without `mobile_to_desktop`, aren't matched.
+/// Joined versions like `15.2-15.3` are matched as ranges,
+/// and adjacent versions are compressed into a single range.
+///
+/// Generated regex doesn't use lookaround or backreference,
+/// so it can be used by most regex engines, including RE2 which is used by many CDNs.
+///
+/// Node.js and Safari Technology Preview can't be recognized from User-Agent,
+/// so they're reported by [`UserAgentRegex::unsupported_browsers`].
+///
+/// ```
+/// use browserslist::{resolve, Opts, UserAgentOpts, UserAgentRegex};
+///
+/// let distribs = resolve(["firefox 115-116, ios_saf 15.2-15.3"], &Opts::new()).unwrap();
+/// let regex = UserAgentRegex::from_distribs(&distribs, &UserAgentOpts::new());
+///
+/// assert_eq!(regex.browsers().map(|(name, _)| name).collect::<Vec<_>>(), ["firefox", "ios_saf"]);
+/// assert!(regex.to_string().contains(r"Firefox/"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UserAgentRegex {
+    browsers: Vec<(&'static str, String)>,
+    unsupported: BTreeSet<&'static str>,
+}
+
+impl UserAgentRegex {
+    /// Generate regex from resolved distribs.
+    pub fn from_distribs(distribs: &[Distrib], opts: &UserAgentOpts) -> Self {
+        let depth = opts.depth() as usize;
+
+        let mut unsupported = BTreeSet::new();
+        let mut intervals = BTreeMap::<&'static str, Vec<Interval>>::new();
+        for distrib in distribs {
+            if templates(distrib.name()).is_empty() {
+                unsupported.insert(distrib.name());
+                continue;
+            }
+            let interval = match distrib.browser_version() {
+                BrowserVersion::Single(version) => Interval {
+                    lower: lower_bound(&version, depth),
+                    upper: upper_bound(&version, depth),
+                },
+                BrowserVersion::Range(lower, upper) => Interval {
+                    lower: lower_bound(&lower, depth),
+                    upper: upper_bound(&upper, depth),
+                },
+                BrowserVersion::All => Interval {
+                    lower: vec![0; depth],
+                    upper: vec![ANY; depth],
+                },
+                BrowserVersion::TechnologyPreview => {
+                    unsupported.insert(distrib.name());
+                    continue;
+                }
+            };
+            intervals.entry(distrib.name()).or_default().push(interval);
+        }
+
+        let browsers = intervals
+            .into_iter()
+            .map(|(name, mut intervals)| {
+                if opts.allow_higher_versions {
+                    intervals.iter_mut().for_each(|interval| {
+                        interval.upper.iter_mut().for_each(|c| *c = ANY);
+                    });
+                }
+                let intervals = merge(intervals);
+                // Android browser before Android 5 uses version of Android,
+                // while Android WebView uses version of Chrome
+                let regex = templates(name)
+                    .iter()
+                    .filter_map(|template| {
+                        let versions = intervals
+                            .iter()
+                            .filter(|interval| match template.android {
+                                Some(Android::Legacy) => interval.lower[0] < 5,
+                                Some(Android::WebView) => {
+                                    interval.lower[0] >= 5 || interval.upper[0] == ANY
+                                }
+                                None => true,
+                            })
+                            .map(|interval| match template.android {
+                                Some(Android::WebView) if interval.lower[0] < 5 => {
+                                    interval_regex(&vec![0; depth], &interval.upper, template.sep)
+                                }
+                                _ => interval_regex(&interval.lower, &interval.upper, template.sep),
+                            })
+                            .collect::<Vec<_>>();
+                        if versions.is_empty() {
+                            None
+                        } else {
+                            Some(format!(
+                                "{}{}{}",
+                                template.prefix,
+                                alternation(versions),
+                                template.suffix
+                            ))
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                (name, alternation(regex))
+            })
+            .collect();
+
+        Self {
+            browsers,
+            unsupported,
+        }
+    }
+
+    /// Return regex of each browser, ordered by browser name.
+    pub fn browsers(&self) -> impl Iterator<Item = (&'static str, &str)> + '_ {
+        self.browsers
+            .iter()
+            .map(|(name, regex)| (*name, regex.as_str()))
+    }
+
+    /// Return browsers which can't be recognized from User-Agent, ordered by name.
+    pub fn unsupported_browsers(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.unsupported.iter().copied()
+    }
+}
+
+impl fmt::Display for UserAgentRegex {
+    /// Format as a single regex which combines regex of all browsers.
+    ///
+    /// If there're no browsers, it's a regex which never matches.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.browsers.is_empty() {
+            return write!(f, "[^\\s\\S]");
+        }
+        let regex = self
+            .browsers
+            .iter()
+            .map(|(_, regex)| regex.clone())
+            .collect::<Vec<_>>();
+        write!(f, "{}", alternation(regex))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Android {
+    Legacy,
+    WebView,
+}
+
+struct Template {
+    prefix: &'static str,
+    suffix: &'static str,
+    sep: &'static str,
+    android: Option<Android>,
+}
+
+impl Template {
+    const fn new(prefix: &'static str, suffix: &'static str) -> Self {
+        Self {
+            prefix,
+            suffix,
+            sep: r"\.",
+            android: None,
+        }
+    }
+}
+
+/// Regex templates of each browser, which are consistent with User-Agent parser.
+const TEMPLATES: &[(&str, &[Template])] = &[
+    (
+        "chrome",
+        &[Template::new(
+            r"\((?:Windows|Macintosh|X11|CrOS)[^)]*\) AppleWebKit/[\d.]+ \(KHTML, like Gecko\) Chrome/",
+            r" Safari/[\d.]+$",
+        )],
+    ),
+    (
+        "and_chr",
+        &[Template::new(
+            r"Android[^)]*\) AppleWebKit/[\d.]+ \(KHTML, like Gecko\) Chrome/",
+            r" (?:Mobile )?Safari/[\d.]+$",
+        )],
+    ),
+    (
+        "android",
+        &[
+            Template {
+                prefix: r"Android ",
+                suffix: r"[^)]*\) AppleWebKit/[\d.]+ \(KHTML, like Gecko\) Version/[\d.]+(?: Chrome/[\d.]+)? (?:Mobile )?Safari/[\d.]+$",
+                sep: r"\.",
+                android: Some(Android::Legacy),
+            },
+            Template {
+                prefix: r"Android (?:[5-9]|[1-9]\d)[^)]*\) AppleWebKit/[\d.]+ \(KHTML, like Gecko\) Version/[\d.]+ Chrome/",
+                suffix: r" (?:Mobile )?Safari/[\d.]+$",
+                sep: r"\.",
+                android: Some(Android::WebView),
+            },
+        ],
+    ),
+    (
+        "edge",
+        &[Template::new(
+            r"\) AppleWebKit/[\d.]+ \(KHTML, like Gecko\) Chrome/[\d.]+ (?:Mobile )?Safari/[\d.]+ Edge?/",
+            "$",
+        )],
+    ),
+    (
+        "opera",
+        &[Template::new(
+            r"\((?:Windows|Macintosh|X11)[^)]*\) AppleWebKit/[\d.]+ \(KHTML, like Gecko\) Chrome/[\d.]+ Safari/[\d.]+ OPR/",
+            "$",
+        )],
+    ),
+    (
+        "op_mob",
+        &[Template::new(
+            r"Android[^)]*\) AppleWebKit/[\d.]+ \(KHTML, like Gecko\) Chrome/[\d.]+ (?:Mobile )?Safari/[\d.]+ OPR/",
+            "$",
+        )],
+    ),
+    (
+        "firefox",
+        &[Template::new(
+            r"\((?:Windows|Macintosh|X11)[^)]*\) Gecko/\d+ Firefox/",
+            "$",
+        )],
+    ),
+    (
+        "and_ff",
+        &[Template::new(
+            r"\(Android[^)]*\) Gecko/[\d.]+ Firefox/",
+            "$",
+        )],
+    ),
+    (
+        "safari",
+        &[Template::new(
+            r"\(Macintosh[^)]*\) AppleWebKit/[\d.]+ \(KHTML, like Gecko\) Version/",
+            r" Safari/[\d.]+$",
+        )],
+    ),
+    (
+        "ios_saf",
+        &[Template {
+            prefix: r"\((?:iPhone|iPad|iPod)[^)]* OS ",
+            suffix: r" like Mac OS X\)",
+            sep: "_",
+            android: None,
+        }],
+    ),
+    (
+        "ie",
+        &[
+            Template::new(r"MSIE ", r"; Windows NT"),
+            Template::new(
+                r"\(Windows NT [^)]*Trident/7\.0;[^)]* rv:",
+                r"\) like Gecko$",
+            ),
+        ],
+    ),
+    ("ie_mob", &[Template::new(r"IEMobile/", r"[;)]")]),
+    ("samsung", &[Template::new(r"SamsungBrowser/", " ")]),
+    ("and_uc", &[Template::new(r"UCBrowser/", END)]),
+    ("and_qq", &[Template::new(r"MQQBrowser/", END)]),
+    (
+        "baidu",
+        &[Template::new(r"(?:baidubrowser|bdbrowser)/", END)],
+    ),
+    ("kaios", &[Template::new(r"KAIOS/", END)]),
+    (
+        "bb",
+        &[Template::new(r"(?:BB10|BlackBerry).*Version/", END)],
+    ),
+    ("op_mini", &[Template::new(r"Opera Mini/", END)]),
+];
+
+fn templates(name: &str) -> &'static [Template] {
+    TEMPLATES
+        .iter()
+        .find(|(browser, _)| *browser == name)
+        .map_or(&[], |(_, templates)| templates)
+}
+
+/// Range of versions, whose components are compared in lexicographic order.
+struct Interval {
+    lower: Vec<u32>,
+    upper: Vec<u32>,
+}
+
+/// Return the lowest components of the given version, with omitted components as `0`.
+fn lower_bound(version: &VersionNumber, depth: usize) -> Vec<u32> {
+    let components = depth.min(version.components() as usize);
+    [version.major(), version.minor(), version.patch()]
+        .iter()
+        .enumerate()
+        .take(depth)
+        .map(|(i, c)| if i < components { *c } else { 0 })
+        .collect()
+}
+
+/// Return the highest components of the given version, with omitted components as any number.
+fn upper_bound(version: &VersionNumber, depth: usize) -> Vec<u32> {
+    let components = depth.min(version.components() as usize);
+    [version.major(), version.minor(), version.patch()]
+        .iter()
+        .enumerate()
+        .take(depth)
+        .map(|(i, c)| if i < components { *c } else { ANY })
+        .collect()
+}
+
+/// Sort intervals, then merge overlapped or adjacent intervals.
+fn merge(mut intervals: Vec<Interval>) -> Vec<Interval> {
+    intervals.sort_by(|a, b| a.lower.cmp(&b.lower));
+    let mut merged = Vec::<Interval>::with_capacity(intervals.len());
+    for interval in intervals {
+        match merged.last_mut() {
+            Some(last) if successor(&last.upper).map_or(true, |next| interval.lower <= next) => {
+                if interval.upper > last.upper {
+                    last.upper = interval.upper;
+                }
+            }
+            _ => merged.push(interval),
+        }
+    }
+    merged
+}
+
+/// Return the lowest version which is greater than all versions in the given upper bound,
+/// or `None` if it accepts any version.
+fn successor(upper: &[u32]) -> Option<Vec<u32>> {
+    let last = upper.iter().rposition(|c| *c != ANY)?;
+    let mut next = upper.to_vec();
+    next[last] = next[last].checked_add(1)?;
+    next[last + 1..].iter_mut().for_each(|c| *c = 0);
+    Some(next)
+}
+
+/// Generate regex for versions between the given bounds, starting with a component.
+///
+/// Components after the bounds are accepted as any numbers,
+/// and omitted components are treated as `0`.
+fn interval_regex(lower: &[u32], upper: &[u32], sep: &str) -> String {
+    let rest = |lower: &[u32], upper: &[u32]| -> String {
+        if lower.iter().all(|c| *c == 0) && upper.iter().all(|c| *c == ANY) {
+            format!("(?:{}\\d+)*", sep)
+        } else if lower.iter().all(|c| *c == 0) {
+            format!("(?:{}{})?", sep, interval_regex(lower, upper, sep))
+        } else {
+            format!("{}{}", sep, interval_regex(lower, upper, sep))
+        }
+    };
+    let any = format!("(?:{}\\d+)*", sep);
+    let zeros = vec![0; lower.len() - 1];
+    let anys = vec![ANY; lower.len() - 1];
+
+    let (low, high) = (lower[0], upper[0]);
+    if low == high {
+        return format!("{}{}", low, rest(&lower[1..], &upper[1..]));
+    }
+
+    let mut branches = vec![];
+    let middle_start = if lower[1..].iter().all(|c| *c == 0) {
+        low
+    } else {
+        branches.push(format!("{}{}", low, rest(&lower[1..], &anys)));
+        low + 1
+    };
+    let middle_end = if high == ANY || upper[1..].iter().all(|c| *c == ANY) {
+        Some(high)
+    } else {
+        high.checked_sub(1)
+    };
+    if let Some(middle_end) = middle_end.filter(|end| middle_start <= *end) {
+        branches.push(format!("{}{}", number_regex(middle_start, middle_end), any));
+    }
+    if high != ANY && upper[1..].iter().any(|c| *c != ANY) {
+        branches.push(format!("{}{}", high, rest(&zeros, &upper[1..])));
+    }
+    alternation(branches)
+}
+
+/// Generate regex for integers between the given bounds without leading zeros.
+/// If the upper bound is [`ANY`], there's no upper bound.
+fn number_regex(low: u32, high: u32) -> String {
+    let low_digits = low.to_string();
+    if high == ANY {
+        let max_of_digits = 10u32
+            .checked_pow(low_digits.len() as u32)
+            .map_or(u32::MAX - 1, |n| n - 1);
+        return alternation(vec![
+            number_regex(low, max_of_digits),
+            format!("[1-9]\\d{{{},}}", low_digits.len()),
+        ]);
+    }
+
+    let high_digits = high.to_string();
+    if low_digits.len() == high_digits.len() {
+        return digits_regex(low_digits.as_bytes(), high_digits.as_bytes());
+    }
+    // split by number of digits, like `5-123` into `5-9`, `10-99` and `100-123`
+    let mut branches = vec![];
+    let mut start = low;
+    while start <= high {
+        let digits = start.to_string().len() as u32;
+        let end = 10u32
+            .checked_pow(digits)
+            .map_or(high, |n| (n - 1).min(high));
+        branches.push(digits_regex(
+            start.to_string().as_bytes(),
+            end.to_string().as_bytes(),
+        ));
+        match end.checked_add(1) {
+            Some(next) => start = next,
+            None => break,
+        }
+    }
+    alternation(branches)
+}
+
+/// Generate regex for numbers between two numbers with the same count of digits.
+fn digits_regex(low: &[u8], high: &[u8]) -> String {
+    if low.is_empty() {
+        return String::new();
+    }
+    let (l, h) = (low[0], high[0]);
+    if low[1..].iter().all(|d| *d == b'0') && high[1..].iter().all(|d| *d == b'9') {
+        return format!("{}{}", digit_class(l, h), repeat_digit(low.len() - 1));
+    }
+    if l == h {
+        return format!("{}{}", l as char, digits_regex(&low[1..], &high[1..]));
+    }
+
+    let nines = vec![b'9'; low.len() - 1];
+    let zeros = vec![b'0'; low.len() - 1];
+    let mut branches = vec![format!("{}{}", l as char, digits_regex(&low[1..], &nines))];
+    if l + 1 < h {
+        branches.push(format!(
+            "{}{}",
+            digit_class(l + 1, h - 1),
+            repeat_digit(low.len() - 1)
+        ));
+    }
+    branches.push(format!("{}{}", h as char, digits_regex(&zeros, &high[1..])));
+    alternation(branches)
+}
+
+fn digit_class(low: u8, high: u8) -> String {
+    match high - low {
+        0 => (low as char).to_string(),
+        9 => "\\d".to_string(),
+        _ => format!("[{}-{}]", low as char, high as char),
+    }
+}
+
+fn repeat_digit(count: usize) -> String {
+    match count {
+        0 => String::new(),
+        1 => "\\d".to_string(),
+        count => format!("\\d{{{}}}", count),
+    }
+}
+
+fn alternation(branches: Vec<String>) -> String {
+    if branches.len() == 1 {
+        branches.into_iter().next().unwrap_or_default()
+    } else {
+        format!("(?:{})", branches.join("|"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matches_user_agent, opts::Opts, resolve};
+    use regex::Regex;
+    use test_case::test_case;
+
+    const USER_AGENTS: &[&str] = &[
+        // Chrome
+        "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/119.0.6045.159 Safari/537.36",
+        "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/118.0.0.0 Safari/537.36",
+        "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/100.0.4896.127 Safari/537.36",
+        "Mozilla/5.0 (X11; CrOS x86_64 14541.0.0) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/99.0.4844.51 Safari/537.36",
+        // Chrome for Android and Android WebView
+        "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/119.0.0.0 Mobile Safari/537.36",
+        "Mozilla/5.0 (Linux; Android 13; SM-X900) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/100.0.4896.127 Safari/537.36",
+        "Mozilla/5.0 (Linux; Android 10; K; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/119.0.6045.163 Mobile Safari/537.36",
+        "Mozilla/5.0 (Linux; U; Android 4.4.4; en-us; Nexus 5 Build/KTU84P) AppleWebKit/534.30 (KHTML, like Gecko) Version/4.0 Mobile Safari/534.30",
+        "Mozilla/5.0 (Linux; Android 4.4.2; Nexus 4 Build/KOT49H) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/30.0.0.0 Mobile Safari/537.36",
+        "Mozilla/5.0 (Linux; U; Android 2.2; en-us; Nexus One Build/FRF91) AppleWebKit/533.1 (KHTML, like Gecko) Version/4.0 Mobile Safari/533.1",
+        // Edge
+        "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/119.0.0.0 Safari/537.36 Edg/119.0.2151.72",
+        "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/70.0.3538.102 Safari/537.36 Edge/18.17763",
+        // Opera
+        "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/119.0.0.0 Safari/537.36 OPR/105.0.0.0",
+        "Mozilla/5.0 (Linux; Android 10; VOG-L29) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/119.0.0.0 Mobile Safari/537.36 OPR/73.1.2254.68016",
+        "Opera/9.80 (Android; Opera Mini/36.2.2254/119.132; U; id) Presto/2.12.423 Version/12.16",
+        // Firefox
+        "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:121.0) Gecko/20100101 Firefox/121.0",
+        "Mozilla/5.0 (X11; Linux x86_64; rv:120.0) Gecko/20100101 Firefox/120.0",
+        "Mozilla/5.0 (Macintosh; Intel Mac OS X 10.15; rv:115.0) Gecko/20100101 Firefox/115.0",
+        "Mozilla/5.0 (Android 13; Mobile; rv:119.0) Gecko/119.0 Firefox/119.0",
+        // Safari
+        "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/15.4 Safari/605.1.15",
+        "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.0 Safari/605.1.15",
+        "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/16.6.1 Safari/605.1.15",
+        "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/15.2 Safari/605.1.15",
+        // Safari on iOS
+        "Mozilla/5.0 (iPhone; CPU iPhone OS 15_2 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/15.2 Mobile/15E148 Safari/604.1",
+        "Mozilla/5.0 (iPhone; CPU iPhone OS 15_3_1 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/15.3 Mobile/15E148 Safari/604.1",
+        "Mozilla/5.0 (iPhone; CPU iPhone OS 15_4 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/15.4 Mobile/15E148 Safari/604.1",
+        "Mozilla/5.0 (iPhone; CPU iPhone OS 16_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/16.0 Mobile/15E148 Safari/604.1",
+        "Mozilla/5.0 (iPad; CPU OS 17_1_2 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) CriOS/119.0.6045.169 Mobile/15E148 Safari/604.1",
+        "Mozilla/5.0 (iPhone; CPU iPhone OS 14_8 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) FxiOS/39.0 Mobile/15E148 Safari/605.1.15",
+        // Internet Explorer
+        "Mozilla/5.0 (Windows NT 6.1; WOW64; Trident/7.0; rv:11.0) like Gecko",
+        "Mozilla/5.0 (compatible; MSIE 10.0; Windows NT 6.1; Trident/6.0)",
+        "Mozilla/4.0 (compatible; MSIE 8.0; Windows NT 5.1; Trident/4.0)",
+        "Mozilla/5.0 (compatible; MSIE 10.0; Windows Phone 8.0; Trident/6.0; IEMobile/10.0; ARM; Touch; NOKIA; Lumia 920)",
+        // others
+        "Mozilla/5.0 (Linux; Android 13; SM-S901B) AppleWebKit/537.36 (KHTML, like Gecko) SamsungBrowser/23.0 Chrome/115.0.0.0 Mobile Safari/537.36",
+        "Mozilla/5.0 (Linux; U; Android 10; en-US; RMX1911 Build/QKQ1.200209.002) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/100.0.4896.58 UCBrowser/15.5.0.1348 Mobile Safari/537.36",
+        "Mozilla/5.0 (Mobile; LYF/F300B/LYF-F300B-001-01-15-130718-i;Android; rv:48.0) Gecko/48.0 Firefox/48.0 KAIOS/2.5",
+        "Mozilla/5.0 (BB10; Touch) AppleWebKit/537.35+ (KHTML, like Gecko) Version/10.3.3.2205 Mobile Safari/537.35+",
+        "curl/8.4.0",
+    ];
+
+    #[test_case("chrome 100-119, firefox 115, edge 119, opera 105", Opts::new(); "desktop")]
+    #[test_case("ios_saf 15.2-15.3, ios_saf 16.0, safari 15.2-15.3, safari 15.4, safari tp", Opts::new(); "joined versions")]
+    #[test_case("android 4.4.3-4.4.4, android 119, and_chr 119, and_ff 119", Opts::new(); "android")]
+    #[test_case("and_chr 100, and_ff 119", Opts::new().mobile_to_desktop(true).clone(); "mobile to desktop")]
+    #[test_case("ie 8, ie 11, ie_mob 10, op_mini all, samsung 23, kaios 2.5, bb 10", Opts::new(); "others")]
+    #[test_case("defaults, node 18", Opts::new(); "defaults")]
+    fn consistent_with_parser(query: &str, opts: Opts) {
+        let distribs = resolve([query], &opts).unwrap();
+        let mut ua_opts = UserAgentOpts::new();
+        let mut matched = 0;
+        for (allow_higher_versions, ignore_patch, ignore_minor) in [
+            (false, true, false),
+            (false, false, false),
+            (false, true, true),
+            (true, true, false),
+            (true, false, false),
+        ] {
+            ua_opts
+                .allow_higher_versions(allow_higher_versions)
+                .ignore_patch(ignore_patch)
+                .ignore_minor(ignore_minor);
+            let regex = UserAgentRegex::from_distribs(&distribs, &ua_opts);
+            let compiled = Regex::new(&regex.to_string()).unwrap();
+            for ua in USER_AGENTS {
+                if compiled.is_match(ua) {
+                    matched += 1;
+                }
+                assert_eq!(
+                    compiled.is_match(ua),
+                    matches_user_agent(ua, &distribs, &ua_opts),
+                    "{ua}\nregex: {regex}\noptions: {ua_opts:?}"
+                );
+            }
+        }
+        assert!(matched > 0);
+    }
+
+    #[test]
+    fn parts_and_unsupported() {
+        let distribs = resolve(["chrome 119, safari tp, node 18"], &Opts::new()).unwrap();
+        let regex = UserAgentRegex::from_distribs(&distribs, &UserAgentOpts::new());
+        assert_eq!(
+            regex.browsers().map(|(name, _)| name).collect::<Vec<_>>(),
+            ["chrome"]
+        );
+        assert_eq!(
+            regex.unsupported_browsers().collect::<Vec<_>>(),
+            ["node", "safari"]
+        );
+
+        let regex = UserAgentRegex::from_distribs(&[], &UserAgentOpts::new());
+        assert!(!Regex::new(&regex.to_string()).unwrap().is_match(""));
+    }
+
+    #[test]
+    fn compression() {
+        let distribs = resolve(["chrome 100-119"], &Opts::new()).unwrap();
+        let regex = UserAgentRegex::from_distribs(&distribs, &UserAgentOpts::new());
+        let (_, chrome) = regex.browsers().next().unwrap();
+        assert!(
+            chrome.contains(r"Chrome/1[0-1]\d(?:\.\d+)* Safari/"),
+            "{chrome}"
+        );
+    }
+
+    #[test_case(0, 9)]
+    #[test_case(5, 123)]
+    #[test_case(17, 17)]
+    #[test_case(18, 1234)]
+    #[test_case(99, 100)]
+    #[test_case(4, ANY)]
+    #[test_case(47, ANY)]
+    fn numbers(low: u32, high: u32) {
+        let regex = Regex::new(&format!("^(?:{})$", number_regex(low, high))).unwrap();
+        for n in 0..3000 {
+            assert_eq!(
+                regex.is_match(&n.to_string()),
+                low <= n && n <= high,
+                "{n} in {low}..={high}: {regex}"
+            );
+        }
+    }
+}
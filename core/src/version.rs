@@ -0,0 +1,501 @@
+use crate::error::Error;
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+};
+use core::{cmp::Ordering, fmt, str::FromStr};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Version of browser or Node.js, as it appears in results of browserslist.
+///
+/// It can be parsed from string and be formatted back to the same string:
+///
+/// ```
+/// use browserslist::BrowserVersion;
+///
+/// let version: BrowserVersion = "15.2-15.3".parse().unwrap();
+/// assert!(matches!(version, BrowserVersion::Range(..)));
+/// assert_eq!(version.to_string(), "15.2-15.3");
+///
+/// assert_eq!("TP".parse(), Ok(BrowserVersion::TechnologyPreview));
+/// assert_eq!("all".parse(), Ok(BrowserVersion::All));
+/// ```
+///
+/// A string containing `-` is parsed as a range only if both sides are numeric versions
+/// with the same number of components, and the lower bound is less than the upper bound.
+/// Otherwise, the part after `-` is a prerelease label,
+/// so `1.0.0-2` is version `1.0.0` with prerelease label `2`, not a range from `1.0.0` to `2`.
+///
+/// ```
+/// use browserslist::BrowserVersion;
+///
+/// assert!(matches!("4.4.3-4.4.4".parse(), Ok(BrowserVersion::Range(..))));
+/// assert!(matches!("1.0.0-2".parse(), Ok(BrowserVersion::Single(..))));
+/// ```
+///
+/// ## Ordering
+///
+/// Versions are totally ordered as below:
+///
+/// - Single versions and ranges are ordered by their lower bounds.
+///   If lower bounds are numerically equal (such as `1.0.0` and `1.0-1.2`), single version goes first,
+///   then ranges are ordered by their upper bounds.
+/// - `TP` (Safari Technology Preview) is greater than any single version or range.
+/// - `all` (used by Opera Mini) is greater than anything else.
+///
+/// ```
+/// use browserslist::BrowserVersion;
+///
+/// let parse = |s: &str| s.parse::<BrowserVersion>().unwrap();
+///
+/// assert!(parse("15.1") < parse("15.2-15.3"));
+/// assert!(parse("15.2") < parse("15.2-15.3"));
+/// assert!(parse("15.2-15.3") < parse("15.4"));
+/// assert!(parse("17.0") < parse("TP"));
+/// assert!(parse("TP") < parse("all"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BrowserVersion {
+    /// Single version, like `15`, `15.4` or `18.0.0`.
+    Single(VersionNumber),
+
+    /// Range of versions which Can I Use doesn't distinguish, like `15.2-15.3`.
+    Range(VersionNumber, VersionNumber),
+
+    /// Safari Technology Preview, which is formatted as `TP`.
+    TechnologyPreview,
+
+    /// All versions, which is formatted as `all`.
+    /// Opera Mini only uses this.
+    All,
+}
+
+impl BrowserVersion {
+    /// Return the single version itself, or the lower bound of range.
+    ///
+    /// For `TP` and `all`, it returns `None`.
+    pub fn lower_bound(&self) -> Option<&VersionNumber> {
+        match self {
+            Self::Single(version) | Self::Range(version, _) => Some(version),
+            Self::TechnologyPreview | Self::All => None,
+        }
+    }
+
+    /// Return the single version itself, or the upper bound of range.
+    ///
+    /// For `TP` and `all`, it returns `None`.
+    pub fn upper_bound(&self) -> Option<&VersionNumber> {
+        match self {
+            Self::Single(version) | Self::Range(_, version) => Some(version),
+            Self::TechnologyPreview | Self::All => None,
+        }
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Single(..) | Self::Range(..) => 0,
+            Self::TechnologyPreview => 1,
+            Self::All => 2,
+        }
+    }
+}
+
+impl Ord for BrowserVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Single(a), Self::Single(b)) => a.cmp(b),
+            (Self::Single(a), Self::Range(b, _)) => a.cmp_numeric(b).then(Ordering::Less),
+            (Self::Range(a, _), Self::Single(b)) => a.cmp_numeric(b).then(Ordering::Greater),
+            (Self::Range(a_lower, a_upper), Self::Range(b_lower, b_upper)) => a_lower
+                .cmp_numeric(b_lower)
+                .then_with(|| a_upper.cmp_numeric(b_upper))
+                .then_with(|| a_lower.cmp(b_lower))
+                .then_with(|| a_upper.cmp(b_upper)),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl PartialOrd for BrowserVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for BrowserVersion {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("tp") {
+            return Ok(Self::TechnologyPreview);
+        }
+        if s.eq_ignore_ascii_case("all") {
+            return Ok(Self::All);
+        }
+
+        // something like `4.4.3-4.4.4` is a range,
+        // while something like `1.0.0-beta.1` is a version with prerelease label
+        if let Some((lower, upper)) = s.split_once('-') {
+            if let (Some(lower), Some(upper)) = (parse_numeric(lower), parse_numeric(upper)) {
+                if lower.components == upper.components && lower < upper {
+                    return Ok(Self::Range(lower, upper));
+                }
+            }
+        }
+
+        s.parse().map(Self::Single)
+    }
+}
+
+impl fmt::Display for BrowserVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Single(version) => write!(f, "{}", version),
+            Self::Range(lower, upper) => write!(f, "{}-{}", lower, upper),
+            Self::TechnologyPreview => write!(f, "TP"),
+            Self::All => write!(f, "all"),
+        }
+    }
+}
+
+impl Serialize for BrowserVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BrowserVersion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Cow::<str>::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Numeric version with up to three components and an optional prerelease label,
+/// like `15`, `15.4`, `18.0.0` or `18.0.0-nightly`.
+///
+/// Omitted components are treated as `0` when comparing,
+/// but they're kept so formatting gives the original string back.
+/// Versions with prerelease label are less than the version without it.
+///
+/// ```
+/// use browserslist::VersionNumber;
+///
+/// let version: VersionNumber = "15.4".parse().unwrap();
+/// assert_eq!(version.major(), 15);
+/// assert_eq!(version.minor(), 4);
+/// assert_eq!(version.patch(), 0);
+/// assert_eq!(version.to_string(), "15.4");
+///
+/// assert!("18.0.0-rc.1".parse::<VersionNumber>().unwrap() < VersionNumber::new(18, 0, 0));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VersionNumber {
+    major: u32,
+    minor: u32,
+    patch: u32,
+    /// How many numeric components are written, from 1 to 3.
+    components: u8,
+    prerelease: Option<String>,
+}
+
+impl VersionNumber {
+    /// Create version with all three components.
+    pub fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+            components: 3,
+            prerelease: None,
+        }
+    }
+
+    /// Return major version.
+    #[inline]
+    pub fn major(&self) -> u32 {
+        self.major
+    }
+
+    /// Return minor version, or `0` if it's omitted.
+    #[inline]
+    pub fn minor(&self) -> u32 {
+        self.minor
+    }
+
+    /// Return patch version, or `0` if it's omitted.
+    #[inline]
+    pub fn patch(&self) -> u32 {
+        self.patch
+    }
+
+    /// Return prerelease label without the leading `-`, if any.
+    #[inline]
+    pub fn prerelease(&self) -> Option<&str> {
+        self.prerelease.as_deref()
+    }
+
+    #[inline]
+    pub(crate) fn components(&self) -> u8 {
+        self.components
+    }
+}
+
+impl VersionNumber {
+    /// Compare without the count of written components,
+    /// so `1.0` and `1.0.0` are equal here.
+    fn cmp_numeric(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (&self.prerelease, &other.prerelease) {
+                (Some(a), Some(b)) => compare_prerelease(a, b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            })
+    }
+}
+
+impl Ord for VersionNumber {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_numeric(other)
+            .then_with(|| self.components.cmp(&other.components))
+    }
+}
+
+impl PartialOrd for VersionNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for VersionNumber {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (numeric, prerelease) = match s.split_once('-') {
+            Some((numeric, prerelease)) => (numeric, Some(prerelease)),
+            None => (s, None),
+        };
+        let mut version =
+            parse_numeric(numeric).ok_or_else(|| Error::InvalidVersion(s.to_string()))?;
+
+        if let Some(prerelease) = prerelease {
+            if prerelease.is_empty()
+                || !prerelease
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'.' || b == b'-')
+            {
+                return Err(Error::InvalidVersion(s.to_string()));
+            }
+            version.prerelease = Some(prerelease.to_string());
+        }
+
+        Ok(version)
+    }
+}
+
+impl fmt::Display for VersionNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.major)?;
+        if self.components > 1 {
+            write!(f, ".{}", self.minor)?;
+        }
+        if self.components > 2 {
+            write!(f, ".{}", self.patch)?;
+        }
+        if let Some(prerelease) = &self.prerelease {
+            write!(f, "-{}", prerelease)?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for VersionNumber {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for VersionNumber {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Cow::<str>::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Parse version which only consists of numeric components, like `15.4`.
+fn parse_numeric(s: &str) -> Option<VersionNumber> {
+    let mut version = VersionNumber {
+        major: 0,
+        minor: 0,
+        patch: 0,
+        components: 0,
+        prerelease: None,
+    };
+    for segment in s.split('.') {
+        if segment.is_empty() || !segment.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let n = segment.parse().ok()?;
+        match version.components {
+            0 => version.major = n,
+            1 => version.minor = n,
+            2 => version.patch = n,
+            _ => return None,
+        }
+        version.components += 1;
+    }
+    Some(version)
+}
+
+/// Compare prerelease labels as Semantic Versioning specifies:
+/// identifiers consisting of only digits are compared numerically
+/// and have lower precedence than others.
+fn compare_prerelease(a: &str, b: &str) -> Ordering {
+    let mut a = a.split('.');
+    let mut b = b.split('.');
+    loop {
+        match (a.next(), b.next()) {
+            (Some(a), Some(b)) => {
+                let ord = match (a.parse::<u64>(), b.parse::<u64>()) {
+                    (Ok(a), Ok(b)) => a.cmp(&b),
+                    (Ok(_), Err(_)) => Ordering::Less,
+                    (Err(_), Ok(_)) => Ordering::Greater,
+                    (Err(_), Err(_)) => a.cmp(b),
+                };
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+            }
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) => return Ordering::Equal,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{
+        caniuse::CANIUSE_BROWSERS, electron::ELECTRON_VERSIONS, node::NODE_VERSIONS,
+    };
+    use alloc::vec::Vec;
+    use test_case::test_case;
+
+    #[test_case("15"; "major only")]
+    #[test_case("15.4"; "major and minor")]
+    #[test_case("18.0.0"; "full")]
+    #[test_case("15.2-15.3"; "range")]
+    #[test_case("4.4.3-4.4.4"; "full range")]
+    #[test_case("18.0.0-nightly20220101"; "prerelease")]
+    #[test_case("1.0.0-rc.1"; "dotted prerelease")]
+    #[test_case("TP"; "technology preview")]
+    #[test_case("all"; "all")]
+    fn round_trip(version: &str) {
+        assert_eq!(
+            version.parse::<BrowserVersion>().unwrap().to_string(),
+            version
+        );
+    }
+
+    #[test_case(""; "empty")]
+    #[test_case("x"; "not a number")]
+    #[test_case("1.2.3.4"; "too many components")]
+    #[test_case("1..2"; "empty component")]
+    #[test_case("+1"; "sign")]
+    #[test_case("1.0-"; "empty prerelease")]
+    #[test_case("99999999999"; "overflow")]
+    fn invalid(version: &str) {
+        assert_eq!(
+            version.parse::<BrowserVersion>(),
+            Err(Error::InvalidVersion(version.into()))
+        );
+    }
+
+    #[test]
+    fn range_or_prerelease() {
+        assert!(matches!(
+            "1.0.0-2.0.0".parse(),
+            Ok(BrowserVersion::Range(..))
+        ));
+        assert!(matches!(
+            "1.0.0-2".parse(),
+            Ok(BrowserVersion::Single(VersionNumber {
+                prerelease: Some(_),
+                ..
+            }))
+        ));
+        assert!(matches!(
+            "2.0.0-1".parse(),
+            Ok(BrowserVersion::Single(VersionNumber {
+                prerelease: Some(_),
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn ordering() {
+        let versions = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1",
+            "1.0",
+            "1.0.0",
+            "1.0.0-1.0.1",
+            "1.0-1.1",
+            "1.0.0-1.1.0",
+            "1.0-1.2",
+            "1.0.1",
+            "1.1",
+            "10",
+            "TP",
+            "all",
+        ]
+        .iter()
+        .map(|version| version.parse::<BrowserVersion>().unwrap())
+        .collect::<Vec<_>>();
+        assert!(versions.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn bundled_versions() {
+        CANIUSE_BROWSERS
+            .values()
+            .flat_map(|stat| stat.version_list.iter().map(|version| version.version))
+            .chain(ELECTRON_VERSIONS.iter().map(|(_, version)| *version))
+            .chain(NODE_VERSIONS.iter().copied())
+            .for_each(|version| {
+                assert_eq!(
+                    version.parse::<BrowserVersion>().unwrap().to_string(),
+                    version
+                );
+            });
+    }
+
+    #[test]
+    fn serde() {
+        let version: BrowserVersion = serde_json::from_str("\"15.2-15.3\"").unwrap();
+        assert_eq!(serde_json::to_string(&version).unwrap(), "\"15.2-15.3\"");
+        assert!(serde_json::from_str::<BrowserVersion>("\"x\"").is_err());
+    }
+}
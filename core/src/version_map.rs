@@ -0,0 +1,242 @@
+use crate::{intern::find_bundled_name, queries::Distrib, version::BrowserVersion};
+use alloc::{borrow::Cow, collections::BTreeMap, vec::Vec};
+use serde::{ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Versions of each browser, collapsed from resolved [`Distrib`]s.
+///
+/// This is useful for tools which only care about
+/// something like "Chrome 87 and above" instead of every single version.
+///
+/// ```
+/// use browserslist::{resolve, BrowserVersionMap, Opts};
+///
+/// let distribs = resolve(["chrome 100-101, safari tp, op_mini all"], &Opts::new()).unwrap();
+/// let map = BrowserVersionMap::from_distribs(&distribs);
+///
+/// let chrome = map.get("chrome").unwrap();
+/// assert_eq!(chrome.min().to_string(), "100");
+/// assert_eq!(chrome.max().to_string(), "101");
+///
+/// assert_eq!(map.get("safari").unwrap().min().to_string(), "TP");
+/// assert_eq!(map.get("op_mini").unwrap().min().to_string(), "all");
+/// ```
+///
+/// With serde, it's serialized as a map from browser name to [`BrowserVersions`].
+/// When deserializing, browser names which aren't in the bundled data are rejected.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BrowserVersionMap(BTreeMap<&'static str, BrowserVersions>);
+
+impl BrowserVersionMap {
+    /// Collapse distribs into versions of each browser.
+    pub fn from_distribs(distribs: &[Distrib]) -> Self {
+        distribs.iter().copied().collect()
+    }
+
+    /// Return versions of the given browser, if any.
+    pub fn get(&self, name: &str) -> Option<&BrowserVersions> {
+        self.0.get(name)
+    }
+
+    /// Iterate browsers and their versions, ordered by browser name.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &BrowserVersions)> {
+        self.0.iter().map(|(name, versions)| (*name, versions))
+    }
+
+    /// Return count of browsers.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check if there're no browsers.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromIterator<Distrib> for BrowserVersionMap {
+    fn from_iter<T: IntoIterator<Item = Distrib>>(iter: T) -> Self {
+        let mut map = BTreeMap::<&'static str, Vec<BrowserVersion>>::new();
+        for distrib in iter {
+            map.entry(distrib.name())
+                .or_default()
+                .push(distrib.browser_version());
+        }
+        Self(
+            map.into_iter()
+                .map(|(name, versions)| (name, BrowserVersions::new(versions)))
+                .collect(),
+        )
+    }
+}
+
+impl Serialize for BrowserVersionMap {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for BrowserVersionMap {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let map = BTreeMap::<Cow<str>, BrowserVersions>::deserialize(deserializer)?;
+        map.into_iter()
+            .map(|(name, versions)| {
+                find_bundled_name(&name)
+                    .map(|name| (name, versions))
+                    .ok_or_else(|| {
+                        serde::de::Error::custom(format_args!("unknown browser: {}", name))
+                    })
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// Versions of a single browser in ascending order, without duplication.
+///
+/// It always contains at least one version.
+/// Ordering of versions follows [`BrowserVersion`],
+/// so joined versions like `15.2-15.3` are placed by their lower bounds,
+/// and `TP` goes after numeric versions.
+///
+/// With serde, it's serialized as an object with `min`, `max` and `versions` fields.
+/// When deserializing, only `versions` is required.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BrowserVersions(Vec<BrowserVersion>);
+
+impl BrowserVersions {
+    fn new(mut versions: Vec<BrowserVersion>) -> Self {
+        versions.sort();
+        versions.dedup();
+        Self(versions)
+    }
+
+    /// Return the minimum version.
+    pub fn min(&self) -> &BrowserVersion {
+        &self.0[0]
+    }
+
+    /// Return the maximum version.
+    pub fn max(&self) -> &BrowserVersion {
+        &self.0[self.0.len() - 1]
+    }
+
+    /// Return all versions in ascending order.
+    pub fn versions(&self) -> &[BrowserVersion] {
+        &self.0
+    }
+}
+
+impl Serialize for BrowserVersions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("BrowserVersions", 3)?;
+        state.serialize_field("min", self.min())?;
+        state.serialize_field("max", self.max())?;
+        state.serialize_field("versions", &self.0)?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for BrowserVersions {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Repr {
+            versions: Vec<BrowserVersion>,
+        }
+
+        let Repr { versions } = Repr::deserialize(deserializer)?;
+        if versions.is_empty() {
+            Err(serde::de::Error::invalid_length(0, &"at least one version"))
+        } else {
+            Ok(Self::new(versions))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{opts::Opts, resolve};
+    use alloc::string::{String, ToString};
+
+    fn map(query: &str) -> BrowserVersionMap {
+        BrowserVersionMap::from_distribs(&resolve([query], &Opts::new()).unwrap())
+    }
+
+    fn versions(map: &BrowserVersionMap, name: &str) -> Vec<String> {
+        map.get(name)
+            .unwrap()
+            .versions()
+            .iter()
+            .map(|version| version.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn joined_versions() {
+        let map = map("ios_saf 16.0, ios_saf 15.2-15.3, ios_saf 14.0-14.4");
+        let ios_saf = map.get("ios_saf").unwrap();
+        assert_eq!(ios_saf.min().to_string(), "14.0-14.4");
+        assert_eq!(ios_saf.min().lower_bound().unwrap().minor(), 0);
+        assert_eq!(ios_saf.max().to_string(), "16.0");
+        assert_eq!(
+            versions(&map, "ios_saf"),
+            ["14.0-14.4", "15.2-15.3", "16.0"]
+        );
+    }
+
+    #[test]
+    fn technology_preview() {
+        let map = map("safari tp, safari 17.0, safari 16.0");
+        let safari = map.get("safari").unwrap();
+        assert_eq!(safari.min().to_string(), "16.0");
+        assert_eq!(safari.max(), &BrowserVersion::TechnologyPreview);
+    }
+
+    #[test]
+    fn group_and_dedup() {
+        let map = map("op_mini all, chrome 101, chrome 100, chrome 101");
+        assert_eq!(map.len(), 2);
+        assert_eq!(versions(&map, "chrome"), ["100", "101"]);
+        assert_eq!(map.get("op_mini").unwrap().min(), &BrowserVersion::All);
+        assert_eq!(
+            map.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+            ["chrome", "op_mini"]
+        );
+    }
+
+    #[test]
+    fn serde() {
+        let map = map("chrome 101, chrome 100, safari tp");
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(
+            json,
+            r#"{"chrome":{"min":"100","max":"101","versions":["100","101"]},"safari":{"min":"TP","max":"TP","versions":["TP"]}}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<BrowserVersionMap>(&json).unwrap(),
+            map
+        );
+
+        let map: BrowserVersionMap =
+            serde_json::from_str(r#"{"firefox":{"versions":["90","89","90"]}}"#).unwrap();
+        assert_eq!(versions(&map, "firefox"), ["89", "90"]);
+        assert!(
+            serde_json::from_str::<BrowserVersionMap>(r#"{"firefox":{"versions":[]}}"#).is_err()
+        );
+        assert!(
+            serde_json::from_str::<BrowserVersionMap>(r#"{"yuru":{"versions":["1.0"]}}"#).is_err()
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use browserslist::{resolve, Opts};
+use browserslist::{excluded_audience, resolve, Opts, Stats};
 use clap::{App, Arg};
 
 fn main() {
@@ -13,20 +13,50 @@ fn main() {
                 .long("ignore-unknown-versions")
                 .takes_value(false),
         )
+        .arg(
+            Arg::with_name("excluded")
+                .long("excluded")
+                .takes_value(true)
+                .value_name("STATS")
+                .help("List browser versions excluded by queries, with usage from `global` or a region"),
+        )
         .arg(Arg::with_name("queries"))
         .get_matches();
 
     match resolve(
-        &vec![matches.value_of("queries").unwrap_or_default()],
+        vec![matches.value_of("queries").unwrap_or_default()],
         Opts::new()
             .mobile_to_desktop(matches.is_present("mobile_to_desktop"))
             .ignore_unknown_versions(matches.is_present("ignore_unknown_versions")),
     ) {
-        Ok(versions) => {
-            for version in versions {
-                println!("{}", version)
+        Ok(versions) => match matches.value_of("excluded") {
+            Some(stats) => {
+                let stats = if stats.eq_ignore_ascii_case("global") {
+                    Stats::Global
+                } else {
+                    Stats::Region(stats)
+                };
+                match excluded_audience(&versions, stats) {
+                    Ok(excluded) => {
+                        for version in excluded.versions() {
+                            println!(
+                                "{} {}\t{:.2}%\t{:.2}%",
+                                version.name(),
+                                version.version(),
+                                version.usage(),
+                                version.cumulative_usage()
+                            )
+                        }
+                    }
+                    Err(error) => eprintln!("{}", error),
+                }
+            }
+            None => {
+                for version in versions {
+                    println!("{}", version)
+                }
             }
-        }
+        },
         Err(error) => eprintln!("{}", error),
     };
 }
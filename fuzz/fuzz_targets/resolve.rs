@@ -0,0 +1,24 @@
+#![no_main]
+
+use browserslist::{resolve, Opts};
+use libfuzzer_sys::fuzz_target;
+
+// Resolving always parses the whole input first,
+// so this covers both the query parser and the query evaluation.
+// Any input must lead to either a result or an error, never a panic.
+fuzz_target!(|input: (bool, bool, Option<usize>, Option<usize>, &str)| {
+    let (mobile_to_desktop, ignore_unknown_versions, max_query_length, max_queries, query) =
+        input;
+
+    let mut opts = Opts::new();
+    opts.mobile_to_desktop(mobile_to_desktop)
+        .ignore_unknown_versions(ignore_unknown_versions)
+        .now(1672531200);
+    if let Some(limit) = max_query_length {
+        opts.max_query_length(limit);
+    }
+    if let Some(limit) = max_queries {
+        opts.max_queries(limit);
+    }
+    let _ = resolve([query], &opts);
+});
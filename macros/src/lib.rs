@@ -0,0 +1,118 @@
+#![deny(clippy::if_not_else)]
+#![deny(clippy::needless_borrow)]
+#![deny(clippy::unimplemented)]
+#![warn(missing_docs)]
+
+//! Resolve [Browserslist](https://github.com/browserslist/browserslist) queries at compile time
+//! with the data bundled in **browserslist-rs**.
+//!
+//! It's re-exported by `browserslist-rs` with the `macros` feature,
+//! which should be used instead of depending on this crate directly.
+//!
+//! ```
+//! use browserslist::Distrib;
+//! use browserslist_macros::browserslist;
+//!
+//! const TARGETS: &[Distrib] = browserslist!("ie <= 6");
+//!
+//! assert_eq!(TARGETS[0].name(), "ie");
+//! assert_eq!(TARGETS[0].version(), "6");
+//! assert_eq!(TARGETS[1].name(), "ie");
+//! assert_eq!(TARGETS[1].version(), "5.5");
+//! ```
+//!
+//! Invalid queries are reported as compile errors at the string literal:
+//!
+//! ```compile_fail
+//! use browserslist_macros::browserslist;
+//!
+//! let targets = browserslist!("yuru 1.0");
+//! ```
+
+use browserslist_core::{resolve, Opts};
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    Ident, LitBool, LitStr, Token,
+};
+
+struct Input {
+    query: LitStr,
+    opts: Opts,
+}
+
+struct Flag {
+    name: Ident,
+    value: LitBool,
+}
+
+impl Parse for Flag {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let value = input.parse()?;
+        Ok(Self { name, value })
+    }
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let query = input.parse()?;
+        let mut opts = Opts::new();
+        if input.parse::<Option<Token![,]>>()?.is_some() {
+            for flag in Punctuated::<Flag, Token![,]>::parse_terminated(input)? {
+                let value = flag.value.value;
+                match flag.name.to_string().as_str() {
+                    "mobile_to_desktop" => opts.mobile_to_desktop(value),
+                    "ignore_unknown_versions" => opts.ignore_unknown_versions(value),
+                    _ => return Err(syn::Error::new(
+                        flag.name.span(),
+                        "unknown option, expected `mobile_to_desktop` or `ignore_unknown_versions`",
+                    )),
+                };
+            }
+        }
+        Ok(Self { query, opts })
+    }
+}
+
+/// Resolve a query at compile time, then expand to `&'static [browserslist::Distrib]`.
+///
+/// The first argument is the query string.
+/// It can be followed by boolean options which have the same meanings as [`Opts`]:
+///
+/// ```
+/// use browserslist_macros::browserslist;
+///
+/// let targets = browserslist!("chromeandroid 53", mobile_to_desktop = true);
+/// assert_eq!(targets[0].to_string(), "and_chr 53");
+/// ```
+///
+/// Queries which depend on environment, such as `current node` and `browserslist config`,
+/// are resolved against the environment of compiler, not the environment of final program.
+/// Resolved browsers must come from bundled data,
+/// so `current node` fails to compile if the version of Node.js isn't known by bundled data.
+#[proc_macro]
+pub fn browserslist(input: TokenStream) -> TokenStream {
+    let Input { query, opts } = parse_macro_input!(input as Input);
+
+    match resolve([query.value()], &opts) {
+        Ok(distribs) => {
+            let names = distribs.iter().map(|distrib| distrib.name());
+            let versions = distribs.iter().map(|distrib| distrib.version());
+            quote! {{
+                const DISTRIBS: &[::browserslist::Distrib] = &[
+                    #( ::browserslist::Distrib::__from_static(#names, #versions), )*
+                ];
+                DISTRIBS
+            }}
+            .into()
+        }
+        Err(error) => syn::Error::new(query.span(), error)
+            .to_compile_error()
+            .into(),
+    }
+}
@@ -0,0 +1,105 @@
+// Records `core/fixtures/browserslist.json` with JavaScript-based Browserslist.
+//
+// Every case already in the fixtures is recorded again.
+// New cases can be added by passing queries as arguments:
+//
+//     node scripts/record-fixtures.js [--mobile-to-desktop] [--ignore-unknown-versions] <query>...
+
+const fs = require('fs')
+const path = require('path')
+const browserslist = require('browserslist')
+
+const root = path.join(__dirname, '..', 'core')
+const fixturesPath = path.join(root, 'fixtures', 'browserslist.json')
+
+function readVersion(file) {
+  return JSON.parse(fs.readFileSync(file, 'utf8')).version
+}
+
+function dataVersions(resolvePackage) {
+  return {
+    caniuse: readVersion(resolvePackage('caniuse-lite', 'caniuse')),
+    electronToChromium: readVersion(
+      resolvePackage('electron-to-chromium', 'electron-to-chromium')
+    ),
+    nodeReleases: readVersion(resolvePackage('node-releases', 'node-releases')),
+  }
+}
+
+const installed = dataVersions((pkg) =>
+  require.resolve(`${pkg}/package.json`, {
+    paths: [path.dirname(require.resolve('browserslist'))],
+  })
+)
+const vendored = dataVersions((_, dir) =>
+  path.join(root, 'vendor', dir, 'package.json')
+)
+if (JSON.stringify(installed) !== JSON.stringify(vendored)) {
+  console.error(
+    'data of installed JavaScript-based Browserslist',
+    installed,
+    "doesn't match vendored data",
+    vendored
+  )
+  process.exit(1)
+}
+
+const args = process.argv.slice(2)
+const flags = {
+  mobileToDesktop: args.includes('--mobile-to-desktop'),
+  ignoreUnknownVersions: args.includes('--ignore-unknown-versions'),
+}
+const queries = args.filter((arg) => !arg.startsWith('--'))
+
+const previous = fs.existsSync(fixturesPath)
+  ? JSON.parse(fs.readFileSync(fixturesPath, 'utf8'))
+  : { cases: [] }
+const cases = previous.cases
+
+// Keep time of previous recording while data is unchanged,
+// so recording again is reproducible.
+const recordedAt =
+  JSON.stringify(previous.data) === JSON.stringify(installed)
+    ? previous.recordedAt
+    : Math.floor(Date.now() / 1000)
+Date.now = () => recordedAt * 1000
+
+for (const query of queries) {
+  cases.push({ query, ...flags })
+}
+
+const key = (c) =>
+  JSON.stringify([c.query, !!c.mobileToDesktop, !!c.ignoreUnknownVersions])
+const recorded = new Map()
+for (const c of cases) {
+  const entry = { query: c.query }
+  if (c.mobileToDesktop) entry.mobileToDesktop = true
+  if (c.ignoreUnknownVersions) entry.ignoreUnknownVersions = true
+  try {
+    entry.expected = browserslist(c.query, {
+      mobileToDesktop: entry.mobileToDesktop,
+      ignoreUnknownVersions: entry.ignoreUnknownVersions,
+    })
+  } catch (error) {
+    if (error.name !== 'BrowserslistError') throw error
+    entry.error = error.message
+  }
+  recorded.set(key(entry), entry)
+}
+
+const sorted = [...recorded.values()].sort((a, b) => {
+  const x = [a.query, !!a.mobileToDesktop, !!a.ignoreUnknownVersions]
+  const y = [b.query, !!b.mobileToDesktop, !!b.ignoreUnknownVersions]
+  for (let i = 0; i < x.length; i++) {
+    if (x[i] < y[i]) return -1
+    if (x[i] > y[i]) return 1
+  }
+  return 0
+})
+
+const fixtures = {
+  data: installed,
+  recordedAt,
+  cases: sorted,
+}
+fs.writeFileSync(fixturesPath, JSON.stringify(fixtures, null, 2) + '\n')
@@ -1,7 +1,4 @@
-#![allow(clippy::float_cmp)]
-#![deny(clippy::if_not_else)]
-#![deny(clippy::needless_borrow)]
-#![deny(clippy::unimplemented)]
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 
 //! **browserslist-rs** is a Rust-based implementation of [Browserslist](https://github.com/browserslist/browserslist).
@@ -50,6 +47,11 @@
 //! );
 //! ```
 //!
+//! ## Compile-time queries
+//!
+//! With the `macros` feature, the `browserslist!` macro resolves a query at compile time
+//! and expands to `&'static [Distrib]`. Invalid queries are reported as compile errors.
+//!
 //! ## WebAssembly
 //!
 //! This crate can be compiled as WebAssembly, without configuring any features manually.
@@ -57,108 +59,36 @@
 //! Please note that browser and Deno can run WebAssembly,
 //! but those environments aren't Node.js,
 //! so you will receive an error when querying `current node` in those environments.
+//!
+//! ## `no_std`
+//!
+//! Parsing queries and resolving them with bundled data only require `alloc`.
+//! Features which access file system, environment variables, processes or system clock
+//! are behind the `std` feature, which is enabled by default.
+//!
+//! Without `std`, configuration files aren't loaded (`browserslist config` means `defaults`),
+//! querying `current node` always fails, and current time must be provided via [`Opts::now`]
+//! when using time-related queries such as `last 2 years`:
+//!
+//! ```
+//! use browserslist::{Opts, resolve};
+//!
+//! // 2023-01-01T00:00:00Z
+//! let distribs = resolve(["last 1 year"], Opts::new().now(1672531200)).unwrap();
+//! assert!(!distribs.is_empty());
+//! ```
 
-use parser::parse_browserslist_query;
-use std::cmp::Ordering;
-#[cfg(target_arch = "wasm32")]
+// This crate is also built as `cdylib` for WebAssembly, which requires a panic handler.
+// Link `std` for it on targets which have `std`, even if the `std` feature is disabled.
+#[cfg(all(not(feature = "std"), not(target_os = "none")))]
+extern crate std;
+
+#[doc(inline)]
+pub use browserslist_core::*;
+#[cfg(feature = "macros")]
+pub use browserslist_macros::browserslist;
+#[cfg(all(feature = "std", target_arch = "wasm32"))]
 pub use wasm::browserslist;
-pub use {error::Error, opts::Opts, queries::Distrib};
 
-#[cfg(not(target_arch = "wasm32"))]
-mod config;
-mod data;
-mod error;
-mod opts;
-mod parser;
-mod queries;
-mod semver;
-#[cfg(test)]
-mod test;
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "std", target_arch = "wasm32"))]
 mod wasm;
-
-/// Resolve browserslist queries.
-///
-/// This is a low-level API.
-/// If you want to load queries from configuration file and
-/// resolve them automatically,
-/// use the higher-level API [`execute`] instead.
-///
-/// ```
-/// use browserslist::{Distrib, Opts, resolve};
-///
-/// let distribs = resolve(["ie <= 6"], &Opts::new()).unwrap();
-/// assert_eq!(distribs[0].name(), "ie");
-/// assert_eq!(distribs[0].version(), "6");
-/// assert_eq!(distribs[1].name(), "ie");
-/// assert_eq!(distribs[1].version(), "5.5");
-/// ```
-pub fn resolve<I, S>(queries: I, opts: &Opts) -> Result<Vec<Distrib>, Error>
-where
-    S: AsRef<str>,
-    I: IntoIterator<Item = S>,
-{
-    let query = queries
-        .into_iter()
-        .enumerate()
-        .fold(String::new(), |mut s, (i, query)| {
-            if i > 0 {
-                s.push_str(", ");
-            }
-            s.push_str(query.as_ref());
-            s
-        });
-
-    let mut distribs = parse_browserslist_query(&query)?
-        .1
-        .into_iter()
-        .enumerate()
-        .try_fold(vec![], |mut distribs, (i, current)| {
-            if i == 0 && current.negated {
-                return Err(Error::NotAtFirst(current.raw.to_string()));
-            }
-
-            let mut dist = queries::query(current.atom, opts)?;
-            if current.negated {
-                distribs.retain(|distrib| !dist.contains(distrib));
-            } else if current.is_and {
-                distribs.retain(|distrib| dist.contains(distrib));
-            } else {
-                distribs.append(&mut dist);
-            }
-
-            Ok::<_, Error>(distribs)
-        })?;
-
-    distribs.sort_by(|a, b| match a.name().cmp(b.name()) {
-        Ordering::Equal => {
-            let version_a = a.version().split('-').next().unwrap();
-            let version_b = b.version().split('-').next().unwrap();
-            version_b
-                .parse::<semver::Version>()
-                .unwrap_or_default()
-                .cmp(&version_a.parse().unwrap_or_default())
-        }
-        ord => ord,
-    });
-    distribs.dedup();
-
-    Ok(distribs)
-}
-
-/// Load queries from configuration with environment information,
-/// then resolve those queries.
-///
-/// If you want to resolve custom queries (not from configuration file),
-/// use the lower-level API [`resolve`] instead.
-///
-/// ```
-/// use browserslist::{Opts, execute};
-///
-/// // when no config found, it use `defaults` query
-/// assert!(!execute(&Opts::new()).unwrap().is_empty());
-/// ```
-#[cfg(not(target_arch = "wasm32"))]
-pub fn execute(opts: &Opts) -> Result<Vec<Distrib>, Error> {
-    resolve(config::load(opts)?, opts)
-}
@@ -1,4 +1,4 @@
-use crate::{opts::Opts, resolve};
+use crate::{resolve, Opts};
 use wasm_bindgen::prelude::*;
 
 #[doc(hidden)]